@@ -5,6 +5,19 @@ The `tablefi` crate provides a simple table to store, manipulate and format tabu
 
 Run `cargo add tablefi` to add the latest version of the `tablefi` crate to your Cargo.toml.
 
+# Features
+
+Optional cargo features add support for other formats:
+
+- `arrow`: convert tables to and from Arrow record batches with `Table::to_record_batch`.
+- `parquet`: read and write tables as parquet files with `Table::read_parquet` and
+  `Table::write_parquet`.
+- `polars`: convert tables to and from polars data frames with `Table::to_dataframe`.
+- `spreadsheet`: read tables from xlsx, xls, xlsb and ods workbooks with `Table::read_spreadsheet`.
+- `sqlite`: read tables from sqlite queries and write them to sqlite tables with
+  `Table::from_sqlite_query` and `Table::write_sqlite`.
+- `xlsx`: write tables as Excel workbooks with `Table::write_xlsx`.
+
 # Example
 
 This example shows how to create a table, add columns and rows, perform arithmetic operations, and print the table as csv.
@@ -60,7 +73,17 @@ $ cargo run --example tablefi-example
 */
 pub mod table;
 
-pub use table::{Cell, Slice, Table};
+pub use table::{BorderStyle, Cell, CellError, CsvEscape, CsvOptions, CsvQuoting, CsvTerminator, Currency, FixedWidthColumn, FixedWidthOptions, HtmlOptions, Inference, JsonOptions, JsonRecordsOptions, JsonStyle, LatexOptions, NegativeStyle, NumberFormat, ParseOptions, QifOptions, RenderOptions, Slice, Table};
+#[cfg(feature = "arrow")]
+pub use table::{ArrowOptions, MixedColumn};
+#[cfg(feature = "polars")]
+pub use table::DataFrameError;
+#[cfg(feature = "spreadsheet")]
+pub use table::{Sheet, SpreadsheetOptions};
+#[cfg(feature = "sqlite")]
+pub use table::SqliteMode;
+#[cfg(feature = "xlsx")]
+pub use table::XlsxOptions;
 
 #[cfg(test)]
 mod tests {
@@ -0,0 +1,324 @@
+use arrow_array::cast::AsArray;
+use arrow_array::types::{ArrowTemporalType, Date32Type, Date64Type, Decimal32Type, Decimal64Type, Decimal128Type, Float32Type, Float64Type, Int8Type, Int16Type, Int32Type, Int64Type, TimestampMicrosecondType, TimestampMillisecondType, TimestampNanosecondType, TimestampSecondType, UInt8Type, UInt16Type, UInt32Type, UInt64Type};
+use arrow_array::{Array, ArrayRef, ArrowPrimitiveType, BooleanArray, Date32Array, Decimal128Array, RecordBatch, StringArray, TimestampMicrosecondArray};
+use arrow_schema::{ArrowError, DataType, Field, Schema, TimeUnit, DECIMAL128_MAX_PRECISION};
+use rust_decimal::Decimal;
+use std::str::FromStr;
+use std::sync::Arc;
+use super::cell::Cell;
+use super::table::Table;
+
+/// How a column with both numbers and text is converted to an Arrow array.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum MixedColumn {
+    /// The column is converted to `Utf8`, with numbers in their presentation.
+    #[default]
+    Utf8,
+    /// The column is converted to `Decimal128`, with text as null.
+    Decimal128,
+    /// Converting the table fails.
+    Error,
+}
+
+/// Options used to convert a `Table` to and from an Arrow `RecordBatch`.
+///
+/// # Examples
+///
+/// ```
+/// use arrow_schema::DataType;
+/// use tablefi::{ArrowOptions, Table};
+///
+/// let mut table: Table = Table::try_from(r#"[["name","amount"],["a","1.5"],["b","$2.25"]]"#).unwrap();
+/// table.set_header(true);
+/// let batch = table.to_record_batch(&ArrowOptions::default()).unwrap();
+/// assert_eq!(batch.schema().field(0).data_type(), &DataType::Utf8);
+/// assert_eq!(batch.schema().field(1).data_type(), &DataType::Decimal128(38, 2));
+/// let table = Table::from_record_batch(&batch, &ArrowOptions::default()).unwrap();
+/// assert_eq!(table.to_string(), r#"[["name","amount"],["a","1.50"],["b","2.25"]]"#);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ArrowOptions {
+    /// Whether the names of the fields are added as the header when reading.  When writing, fields
+    /// are named by the header of a table with `Table::has_header`, or by their position, like
+    /// `column_1`, whatever this option is.
+    pub header: bool,
+    /// How a column with both numbers and text is converted.
+    pub mixed: MixedColumn,
+}
+
+impl Default for ArrowOptions {
+
+    fn default() -> Self {
+        ArrowOptions {
+            header: true,
+            mixed: MixedColumn::Utf8,
+        }
+    }
+
+}
+
+/// Converts cells to a `Decimal128` array with the largest scale of the numbers.  Cells without a
+/// number are null.
+fn decimal_array(cells: &[Cell]) -> Result<ArrayRef, ArrowError> {
+    let scale = cells.iter().filter_map(Cell::to_decimal).map(|d| d.scale()).max().unwrap_or(0);
+    let values = cells.iter()
+        .map(|cell| cell.to_decimal().map(|d| {
+            10_i128.checked_pow(scale - d.scale())
+                .and_then(|factor| d.mantissa().checked_mul(factor))
+                .ok_or_else(|| ArrowError::ComputeError(format!("Number {} does not fit in Decimal128 with scale {}", d, scale)))
+        }).transpose())
+        .collect::<Result<Vec<Option<i128>>, ArrowError>>()?;
+    let array = Decimal128Array::from(values).with_precision_and_scale(DECIMAL128_MAX_PRECISION, scale as i8)?;
+    array.validate_decimal_precision(DECIMAL128_MAX_PRECISION)?;
+    Ok(Arc::new(array))
+}
+
+/// Converts cells to a `Utf8` array.  Empty cells are null.
+fn string_array(cells: &[Cell]) -> ArrayRef {
+    let values: Vec<Option<String>> = cells.iter()
+        .map(|cell| Some(cell.to_string()).filter(|s| !s.is_empty()))
+        .collect();
+    Arc::new(StringArray::from(values))
+}
+
+/// Converts cells to a `Boolean`, `Date32` or `Timestamp` array when every cell with a value is a
+/// boolean, a date or a date and time.  Empty cells are null.
+fn typed_array(cells: &[Cell]) -> Option<ArrayRef> {
+    let mut values = cells.iter().filter(|cell| !cell.is_empty());
+    let array: ArrayRef = match values.next()? {
+        Cell::Bool(_) if values.all(Cell::is_bool) => Arc::new(cells.iter()
+            .map(|cell| match cell {
+                Cell::Bool(b) => Some(*b),
+                _ => None,
+            })
+            .collect::<BooleanArray>()),
+        Cell::Date(_) if values.all(|cell| matches!(cell, Cell::Date(_))) => Arc::new(cells.iter()
+            .map(|cell| match cell {
+                Cell::Date(d) => Some(Date32Type::from_naive_date(*d)),
+                _ => None,
+            })
+            .collect::<Date32Array>()),
+        Cell::DateTime(_) if values.all(|cell| matches!(cell, Cell::DateTime(_))) => Arc::new(cells.iter()
+            .map(|cell| match cell {
+                Cell::DateTime(dt) => Some(dt.and_utc().timestamp_micros()),
+                _ => None,
+            })
+            .collect::<TimestampMicrosecondArray>()),
+        _ => return None,
+    };
+    Some(array)
+}
+
+/// Converts a primitive array of dates or timestamps without a time zone to cells.
+fn temporal_cells<T: ArrowTemporalType>(array: &dyn Array, time: bool) -> Result<Vec<Cell>, ArrowError> where i64: From<T::Native> {
+    let array = array.as_primitive::<T>();
+    (0..array.len())
+        .map(|i| match array.is_null(i) {
+            true => Ok(Cell::Empty),
+            false => match time {
+                true => array.value_as_datetime(i).map(Cell::DateTime),
+                false => array.value_as_date(i).map(Cell::Date),
+            }.ok_or_else(|| ArrowError::CastError(format!("Value {} is out of range for a date", i64::from(array.value(i))))),
+        })
+        .collect()
+}
+
+/// Converts a primitive array of integers to cells.
+fn integer_cells<T: ArrowPrimitiveType>(array: &dyn Array) -> Vec<Cell> where T::Native: Into<Decimal> {
+    array.as_primitive::<T>().iter()
+        .map(|value| value.map(|v| Cell::Number(v.into())).unwrap_or_default())
+        .collect()
+}
+
+/// Converts a primitive array of floats to cells, using the shortest representation of each
+/// float.
+fn float_cells<T: ArrowPrimitiveType>(array: &dyn Array) -> Vec<Cell> where T::Native: ToString {
+    array.as_primitive::<T>().iter()
+        .map(|value| match value {
+            Some(v) => match Decimal::from_str(&v.to_string()) {
+                Ok(d) => Cell::Number(d),
+                Err(_) => Cell::Text(v.to_string()),
+            },
+            None => Cell::default(),
+        })
+        .collect()
+}
+
+/// Converts a primitive array of decimals to cells, keeping the scale of the decimals.
+fn decimal_cells<T: ArrowPrimitiveType>(array: &dyn Array, scale: i8) -> Result<Vec<Cell>, ArrowError> where T::Native: Into<i128> {
+    let scale = u32::try_from(scale).map_err(|_| ArrowError::CastError(format!("Negative decimal scale {} is not supported", scale)))?;
+    array.as_primitive::<T>().iter()
+        .map(|value| value.map(|v| {
+            Decimal::try_from_i128_with_scale(v.into(), scale)
+                .map(Cell::Number)
+                .map_err(|e| ArrowError::CastError(e.to_string()))
+        }).transpose().map(Option::unwrap_or_default))
+        .collect()
+}
+
+/// Converts an Arrow array to cells.
+fn array_cells(array: &dyn Array) -> Result<Vec<Cell>, ArrowError> {
+    let text = |value: Option<&str>| value.filter(|s| !s.is_empty()).map(|s| Cell::Text(s.to_string())).unwrap_or_default();
+    Ok(match array.data_type() {
+        DataType::Utf8 => array.as_string::<i32>().iter().map(text).collect(),
+        DataType::LargeUtf8 => array.as_string::<i64>().iter().map(text).collect(),
+        DataType::Utf8View => array.as_string_view().iter().map(text).collect(),
+        DataType::Boolean => array.as_boolean().iter()
+            .map(|value| value.map(Cell::Bool).unwrap_or_default())
+            .collect(),
+        DataType::Int8 => integer_cells::<Int8Type>(array),
+        DataType::Int16 => integer_cells::<Int16Type>(array),
+        DataType::Int32 => integer_cells::<Int32Type>(array),
+        DataType::Int64 => integer_cells::<Int64Type>(array),
+        DataType::UInt8 => integer_cells::<UInt8Type>(array),
+        DataType::UInt16 => integer_cells::<UInt16Type>(array),
+        DataType::UInt32 => integer_cells::<UInt32Type>(array),
+        DataType::UInt64 => integer_cells::<UInt64Type>(array),
+        DataType::Float32 => float_cells::<Float32Type>(array),
+        DataType::Float64 => float_cells::<Float64Type>(array),
+        DataType::Decimal32(_, scale) => decimal_cells::<Decimal32Type>(array, *scale)?,
+        DataType::Decimal64(_, scale) => decimal_cells::<Decimal64Type>(array, *scale)?,
+        DataType::Decimal128(_, scale) => decimal_cells::<Decimal128Type>(array, *scale)?,
+        DataType::Date32 => temporal_cells::<Date32Type>(array, false)?,
+        DataType::Date64 => temporal_cells::<Date64Type>(array, false)?,
+        DataType::Timestamp(TimeUnit::Second, None) => temporal_cells::<TimestampSecondType>(array, true)?,
+        DataType::Timestamp(TimeUnit::Millisecond, None) => temporal_cells::<TimestampMillisecondType>(array, true)?,
+        DataType::Timestamp(TimeUnit::Microsecond, None) => temporal_cells::<TimestampMicrosecondType>(array, true)?,
+        DataType::Timestamp(TimeUnit::Nanosecond, None) => temporal_cells::<TimestampNanosecondType>(array, true)?,
+        data_type => return Err(ArrowError::NotYetImplemented(format!("Converting {} to cells is not supported", data_type))),
+    })
+}
+
+impl Table {
+
+    /// Converts the table to an Arrow `RecordBatch`, with a field for each column named by the
+    /// header, or by position like `column_1` for a table without a header.
+    ///
+    /// Columns where every cell is a number are converted to `Decimal128` with the largest scale
+    /// of the numbers, and columns of text to `Utf8`.  Columns with both are converted as
+    /// specified by the options.  Columns of booleans are converted to `Boolean`, columns of dates
+    /// to `Date32` and columns of dates and times to `Timestamp` in microseconds without a time
+    /// zone.  Empty cells are null.  Money is text like `USD 1.50`, since a decimal column cannot
+    /// hold its currency.
+    pub fn to_record_batch(&self, options: &ArrowOptions) -> Result<RecordBatch, ArrowError> {
+        let header = self.header();
+        let mut fields: Vec<Field> = Vec::new();
+        let mut arrays: Vec<ArrayRef> = Vec::new();
+        for c in 0..self.cols() {
+            let col: Vec<Cell> = self.col(c).unwrap_or_default().into_iter().collect();
+            let name = match &header {
+                Some(header) => header.cell(c).to_string(),
+                None => format!("column_{}", c + 1),
+            };
+            let cells = col.get(self.body_start()..).unwrap_or_default();
+            let values: Vec<&Cell> = cells.iter().filter(|cell| !cell.to_string().is_empty()).collect();
+            let numbers = values.iter().filter(|cell| cell.is_number()).count();
+            let array = match (numbers, values.len() - numbers, options.mixed) {
+                (0, _, _) => typed_array(cells).unwrap_or_else(|| string_array(cells)),
+                (_, 0, _) | (_, _, MixedColumn::Decimal128) => decimal_array(cells)?,
+                (_, _, MixedColumn::Utf8) => string_array(cells),
+                (_, _, MixedColumn::Error) => {
+                    return Err(ArrowError::InvalidArgumentError(format!("Column {} has both numbers and text", name)));
+                }
+            };
+            fields.push(Field::new(name, array.data_type().clone(), true));
+            arrays.push(array);
+        }
+        RecordBatch::try_new(Arc::new(Schema::new(fields)), arrays)
+    }
+
+    /// Converts an Arrow `RecordBatch` to a table, with a column for each field.
+    ///
+    /// Strings become text and integers, floats and decimals become numbers.  Booleans become
+    /// `Cell::Bool`, dates become `Cell::Date` and timestamps without a time zone become
+    /// `Cell::DateTime`.  Nulls become empty cells.
+    pub fn from_record_batch(batch: &RecordBatch, options: &ArrowOptions) -> Result<Table, ArrowError> {
+        let columns = batch.columns().iter()
+            .map(|array| array_cells(array.as_ref()))
+            .collect::<Result<Vec<Vec<Cell>>, ArrowError>>()?;
+        let mut table = match options.header {
+            true => Table::with_header(batch.schema().fields().iter().map(|field| field.name())),
+            false => Table::new(),
+        };
+        for r in 0..batch.num_rows() {
+            table.push_row(columns.iter().map(|col| col[r].clone()).collect::<Vec<Cell>>());
+        }
+        Ok(table)
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use arrow_array::{BooleanArray, Float64Array, Int64Array};
+    use super::*;
+
+    #[test]
+    fn test_to_record_batch() {
+        let mut table: Table = Table::try_from(r#"[["id","name","amount","mixed"],["1","a","(2.50)","1"],["2","","12.5%","x"],["3","c","","2.5"]]"#).unwrap();
+        table.set_header(true);
+        let batch = table.to_record_batch(&ArrowOptions::default()).unwrap();
+        assert_eq!(batch.num_rows(), 3);
+        let schema = batch.schema();
+        let types: Vec<&DataType> = schema.fields().iter().map(|field| field.data_type()).collect();
+        assert_eq!(types, vec![&DataType::Decimal128(38, 0), &DataType::Utf8, &DataType::Decimal128(38, 3), &DataType::Utf8]);
+        assert_eq!(schema.field(2).name(), "amount");
+        let amounts = batch.column(2).as_primitive::<Decimal128Type>();
+        assert_eq!(amounts.value(0), -2500);
+        assert_eq!(amounts.value(1), 125);
+        assert!(amounts.is_null(2));
+        assert!(batch.column(1).is_null(1));
+        let options = ArrowOptions { mixed: MixedColumn::Error, ..Default::default() };
+        assert!(table.to_record_batch(&options).is_err());
+        // a table without a header has fields named by position, whatever the options
+        table.set_header(false);
+        let options = ArrowOptions { mixed: MixedColumn::Decimal128, ..Default::default() };
+        let batch = table.to_record_batch(&options).unwrap();
+        assert_eq!(batch.num_rows(), 4);
+        assert_eq!(batch.schema().field(0).name(), "column_1");
+        assert_eq!(batch.schema().field(3).data_type(), &DataType::Decimal128(38, 1));
+        let table: Table = Table::try_from(r#"[["79228162514264337593543950335"],["0.0000000000001"]]"#).unwrap();
+        assert!(table.to_record_batch(&ArrowOptions::default()).is_err());
+    }
+
+    #[test]
+    fn test_record_batch_typed() {
+        let mut table: Table = Table::try_from(r#"[["active","date","time","mixed"],[true,"2024-01-31","2024-01-31T09:30:00.5","2024-01-31"],[null,"","2024-02-01 10:00","x"]]"#).unwrap();
+        table.set_header(true);
+        let batch = table.to_record_batch(&ArrowOptions::default()).unwrap();
+        let schema = batch.schema();
+        let types: Vec<&DataType> = schema.fields().iter().map(|field| field.data_type()).collect();
+        assert_eq!(types, vec![&DataType::Boolean, &DataType::Date32, &DataType::Timestamp(TimeUnit::Microsecond, None), &DataType::Utf8]);
+        assert!(batch.column(1).is_null(1));
+        let read = Table::from_record_batch(&batch, &ArrowOptions::default()).unwrap();
+        assert_eq!(read.to_string(), r#"[["active","date","time","mixed"],["true","2024-01-31","2024-01-31T09:30:00.500","2024-01-31"],["","","2024-02-01T10:00:00","x"]]"#);
+        assert_eq!(read.cell(1, 1), table.cell(1, 1));
+        assert_eq!(read.cell(2, 2), table.cell(2, 2));
+        assert!(read.cell(1, 3).unwrap().is_text());
+    }
+
+    #[test]
+    fn test_from_record_batch() {
+        let schema = Schema::new(vec![
+            Field::new("id", DataType::Int64, false),
+            Field::new("rate", DataType::Float64, true),
+            Field::new("active", DataType::Boolean, true),
+        ]);
+        let arrays: Vec<ArrayRef> = vec![
+            Arc::new(Int64Array::from(vec![1, 2])),
+            Arc::new(Float64Array::from(vec![Some(0.1), None])),
+            Arc::new(BooleanArray::from(vec![Some(true), None])),
+        ];
+        let batch = RecordBatch::try_new(Arc::new(schema), arrays).unwrap();
+        let table = Table::from_record_batch(&batch, &ArrowOptions::default()).unwrap();
+        assert_eq!(table.to_string(), r#"[["id","rate","active"],["1","0.1","true"],["2","",""]]"#);
+        assert_eq!(table.cell(1, 1), Some(Cell::Number(Decimal::new(1, 1))));
+        assert_eq!(table.cell(1, 2), Some(Cell::Bool(true)));
+        assert_eq!(table.cell(2, 2), Some(Cell::Empty));
+        let table: Table = Table::try_from(r#"[["$1,234.50","x"],["-3","y"]]"#).unwrap();
+        let options = ArrowOptions { header: false, ..Default::default() };
+        let batch = table.to_record_batch(&options).unwrap();
+        assert_eq!(Table::from_record_batch(&batch, &options).unwrap().to_string(), r#"[["1234.50","x"],["-3.00","y"]]"#);
+    }
+
+}
@@ -1,23 +1,349 @@
-use regex::Regex;
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime, TimeDelta};
+use rust_decimal::prelude::ToPrimitive;
 use rust_decimal::Decimal;
-use serde::{Deserialize, Deserializer, Serialize, Serializer};
-use serde_json::{Value};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::value::RawValue;
+use serde_json::Value;
 use std::ops::{Add, Sub, Mul, Div};
 use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::fmt;
 use std::str::FromStr;
-use std::sync::LazyLock;
+use super::currency::Currency;
 
-static RE_NUMERIC_PATTERN: LazyLock<Regex> = LazyLock::new(|| {
-    Regex::new(r#"^[+-]?(?:(?:(?:\d{1,3}(?:,\d{3})*|\d+)(?:\.\d+)?)|(?:\.\d+))$"#).unwrap()
-});
+/// The ISO 8601 format of a date cell, like `2024-01-31`.
+const DATE_FORMAT: &str = "%Y-%m-%d";
 
-static RE_STRIP_CHARS: LazyLock<Regex> = LazyLock::new(|| {
-    Regex::new(r"[^0-9+.-]").unwrap()
-});
+/// The ISO 8601 format of a date and time cell, like `2024-01-31T09:30:00`.  Fractional seconds
+/// are only written when present.
+const DATETIME_FORMAT: &str = "%Y-%m-%dT%H:%M:%S%.f";
 
-pub const DIV0: &str = "#DIV/0";
+/// The formats accepted when parsing a date and time, with or without seconds.
+const DATETIME_PARSE_FORMATS: [&str; 4] = ["%Y-%m-%dT%H:%M:%S%.f", "%Y-%m-%d %H:%M:%S%.f", "%Y-%m-%dT%H:%M", "%Y-%m-%d %H:%M"];
 
-/// Represents a single cell in a table, which can either contain text or a number.
+const MILLISECONDS_PER_DAY: i64 = 86_400_000;
+
+/// Gives the currency of the result of an operation on two amounts, from their currencies.
+type CurrencyRule = fn(Option<Currency>, Option<Currency>) -> Result<Option<Currency>, CellError>;
+
+/// How the type of a cell is inferred from text.
+///
+/// # Examples
+///
+/// ```
+/// use rust_decimal::Decimal;
+/// use tablefi::{Cell, Inference, JsonOptions, ParseOptions, Table};
+///
+/// let options = ParseOptions { inference: Inference::AlwaysText, ..Default::default() };
+/// assert_eq!(Cell::parse("00123", &options), Cell::Text("00123".to_string()));
+///
+/// // keep leading zeros of the zip codes in the first column
+/// let mut options = JsonOptions::default();
+/// options.parse.column_inference.insert(0, Inference::AlwaysText);
+/// let table = Table::from_json_with(r#"[["00123","1,234"]]"#, &options).unwrap();
+/// assert_eq!(table.cell(0, 0), Some(Cell::Text("00123".to_string())));
+/// assert_eq!(table.cell(0, 1), Some(Cell::Number(Decimal::from(1234))));
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Inference {
+    /// Text that looks like a number becomes a number, `true` or `false` becomes a boolean, an
+    /// ISO 8601 date like `2024-01-31` becomes a date and an Excel error code like `#DIV/0!`
+    /// becomes an error, otherwise text.
+    #[default]
+    Auto,
+    /// Text is always kept as text.
+    AlwaysText,
+    /// Text is expected to be a number.  Text that is not a number is an error when parsing
+    /// strictly, otherwise it is kept as text.
+    AlwaysNumber,
+}
+
+/// Options describing how text is parsed into a `Cell`.
+///
+/// The default options recognise US style numbers like `-1,234.56`.  Presets are available for
+/// common European formats.
+///
+/// Accounting negatives like `(1,234.56)` or `1,234.56-`, currency symbols like `$1,234.56` and
+/// percentages like `12.5%` are also recognised as numbers.  These are stored as
+/// `Cell::Formatted`, which remembers the presentation so the value is written back out the same
+/// way.
+///
+/// # Examples
+///
+/// ```
+/// use rust_decimal::Decimal;
+/// use tablefi::{Cell, ParseOptions};
+///
+/// assert_eq!(Cell::parse("1.234,56", &ParseOptions::german()), Cell::Number(Decimal::new(123456, 2)));
+/// assert_eq!(Cell::parse("1'234.56", &ParseOptions::swiss()), Cell::Number(Decimal::new(123456, 2)));
+/// assert_eq!(Cell::parse("1 234,56", &ParseOptions::french()), Cell::Number(Decimal::new(123456, 2)));
+///
+/// let options = ParseOptions { decimal_separator: ',', group_separators: vec!['_'], ..Default::default() };
+/// assert_eq!(Cell::parse("1_234,56", &options), Cell::Number(Decimal::new(123456, 2)));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseOptions {
+    /// The character separating the integer and fractional parts of a number.
+    pub decimal_separator: char,
+    /// The characters accepted between groups of three digits in the integer part of a number.
+    pub group_separators: Vec<char>,
+    /// The currency symbols accepted before or after a number, like `$1.00` or `1,00 €`.  A symbol
+    /// that is an ISO 4217 code, like `CHF`, gives money in that currency.
+    pub currency_symbols: Vec<String>,
+    /// Whether importing a table fails on a value that cannot be parsed as requested, rather than
+    /// keeping it as text.  This is a number that cannot be held exactly by a `Decimal`, or text
+    /// that is not a number with `Inference::AlwaysNumber`.
+    pub strict: bool,
+    /// How the type of a cell is inferred from text.
+    pub inference: Inference,
+    /// Overrides of the inference for columns by index, like text for ZIP codes or account numbers.
+    pub column_inference: HashMap<usize, Inference>,
+}
+
+impl Default for ParseOptions {
+
+    fn default() -> Self {
+        ParseOptions::us()
+    }
+
+}
+
+impl ParseOptions {
+
+    /// Returns the inference for the column at the specified index.
+    pub fn inference_for(&self, col: usize) -> Inference {
+        self.column_inference.get(&col).copied().unwrap_or(self.inference)
+    }
+
+    /// US and UK style numbers like `1,234.56`.
+    pub fn us() -> Self {
+        ParseOptions {
+            decimal_separator: '.',
+            group_separators: vec![','],
+            currency_symbols: ["US$", "$", "€", "£", "¥", "₹", "CHF"].iter().map(|s| s.to_string()).collect(),
+            strict: false,
+            inference: Inference::Auto,
+            column_inference: HashMap::new(),
+        }
+    }
+
+    /// German style numbers like `1.234,56`.
+    pub fn german() -> Self {
+        ParseOptions {
+            decimal_separator: ',',
+            group_separators: vec!['.'],
+            ..Default::default()
+        }
+    }
+
+    /// Swiss style numbers like `1'234.56`.
+    pub fn swiss() -> Self {
+        ParseOptions {
+            decimal_separator: '.',
+            group_separators: vec!['\'', '\u{2019}'],
+            ..Default::default()
+        }
+    }
+
+    /// French style numbers like `1 234,56`, grouped by a space or a (narrow) no-break space.
+    pub fn french() -> Self {
+        ParseOptions {
+            decimal_separator: ',',
+            group_separators: vec![' ', '\u{a0}', '\u{202f}'],
+            ..Default::default()
+        }
+    }
+
+}
+
+/// An error held by a cell, like the result of dividing by zero.
+///
+/// Errors are written as their Excel error codes, like `#DIV/0!`, and text with these codes is
+/// parsed back into errors.
+///
+/// # Examples
+///
+/// ```
+/// use rust_decimal::Decimal;
+/// use tablefi::{Cell, CellError};
+///
+/// let mut cell = Cell::from("1.50");
+/// cell.div_value(Decimal::ZERO);
+/// assert_eq!(cell, Cell::Error(CellError::DivZero));
+/// assert_eq!(cell.to_string(), "#DIV/0!");
+///
+/// // errors propagate through later arithmetic
+/// assert_eq!(&Cell::from("2") + &cell, Cell::Error(CellError::DivZero));
+/// assert_eq!(Cell::from("#N/A"), Cell::Error(CellError::NA));
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CellError {
+    /// A number was divided by zero, written as `#DIV/0!`.
+    DivZero,
+    /// A value has the wrong type for an operation, written as `#VALUE!`.
+    Value,
+    /// A reference to a cell that does not exist, written as `#REF!`.
+    Ref,
+    /// A value is not available, written as `#N/A`.
+    NA,
+    /// A number is too large or too small to be held, written as `#NUM!`.
+    Overflow,
+}
+
+impl CellError {
+
+    /// Returns the Excel error code, like `#DIV/0!`.
+    pub fn code(&self) -> &'static str {
+        match self {
+            CellError::DivZero => "#DIV/0!",
+            CellError::Value => "#VALUE!",
+            CellError::Ref => "#REF!",
+            CellError::NA => "#N/A",
+            CellError::Overflow => "#NUM!",
+        }
+    }
+
+    /// Returns the error for an Excel error code, like `#DIV/0!`.
+    pub fn from_code(code: &str) -> Option<CellError> {
+        [CellError::DivZero, CellError::Value, CellError::Ref, CellError::NA, CellError::Overflow]
+            .into_iter()
+            .find(|e| e.code() == code)
+    }
+
+}
+
+impl fmt::Display for CellError {
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.code())
+    }
+
+}
+
+impl std::error::Error for CellError {}
+
+/// How a negative number is presented.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum NegativeStyle {
+    /// A leading minus sign, like `-1.00`.
+    #[default]
+    Minus,
+    /// Enclosed by parentheses, like `(1.00)`.
+    Parentheses,
+    /// A trailing minus sign, like `1.00-`.
+    TrailingMinus,
+}
+
+/// The presentation of a number parsed from text, used to write the number back out the same way.
+///
+/// # Examples
+///
+/// ```
+/// use rust_decimal::Decimal;
+/// use tablefi::Cell;
+///
+/// let mut cell = Cell::from("($1,234.56)");
+/// assert_eq!(cell.to_decimal(), Some(Decimal::new(-123456, 2)));
+/// cell.sub_value(Decimal::from(1000));
+/// assert_eq!(cell.to_string(), "($2,234.56)");
+///
+/// let cell = Cell::from("12.5%");
+/// assert_eq!(cell.to_decimal(), Some(Decimal::new(125, 3)));
+/// assert_eq!(cell.to_string(), "12.5%");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NumberFormat {
+    /// Text before the number, like a currency symbol.
+    pub prefix: String,
+    /// Text after the number, like a currency symbol or percent sign.
+    pub suffix: String,
+    /// Whether the number is a percentage, where the value is one hundredth of the presented
+    /// number.
+    pub percent: bool,
+    /// How a negative number is presented.
+    pub negative: NegativeStyle,
+    /// The character separating the integer and fractional parts of the number.
+    pub decimal_separator: char,
+    /// The character separating groups of three digits, if grouped.
+    pub group_separator: Option<char>,
+}
+
+impl Default for NumberFormat {
+
+    fn default() -> Self {
+        NumberFormat {
+            prefix: String::new(),
+            suffix: String::new(),
+            percent: false,
+            negative: NegativeStyle::Minus,
+            decimal_separator: '.',
+            group_separator: None,
+        }
+    }
+
+}
+
+impl NumberFormat {
+
+    /// Formats the value using this presentation.
+    ///
+    /// A percentage too large to be presented as a `Decimal`, like `7e28` as `7e30%`, is formatted
+    /// as a plain number.
+    pub fn format(&self, value: &Decimal) -> String {
+        let mut abs = value.abs();
+        if self.percent {
+            // shift the decimal point two places to the right without changing the digits
+            let shifted = match abs.scale() {
+                scale if scale >= 2 => Decimal::try_from_i128_with_scale(abs.mantissa(), scale - 2),
+                scale => Decimal::try_from_i128_with_scale(abs.mantissa() * 10_i128.pow(2 - scale), 0),
+            };
+            abs = match shifted {
+                Ok(shifted) => shifted,
+                Err(_) => return value.to_string(),
+            };
+        }
+        let digits = abs.to_string();
+        let (int_part, frac_part) = match digits.split_once('.') {
+            Some((int_part, frac_part)) => (int_part, Some(frac_part)),
+            None => (digits.as_str(), None),
+        };
+        let mut number = String::with_capacity(digits.len() + digits.len() / 3);
+        for (i, c) in int_part.chars().enumerate() {
+            if let Some(sep) = self.group_separator {
+                if i > 0 && (int_part.len() - i) % 3 == 0 { number.push(sep); }
+            }
+            number.push(c);
+        }
+        if let Some(frac_part) = frac_part {
+            number.push(self.decimal_separator);
+            number.push_str(frac_part);
+        }
+        let number = format!("{}{}{}", self.prefix, number, self.suffix);
+        match (value.is_sign_negative() && !value.is_zero(), self.negative) {
+            (false, _) => number,
+            (true, NegativeStyle::Minus) => format!("-{}", number),
+            (true, NegativeStyle::Parentheses) => format!("({})", number),
+            (true, NegativeStyle::TrailingMinus) => format!("{}-", number),
+        }
+    }
+
+}
+
+/// Represents a single cell in a table, which can contain text, a number, a boolean, a date, an
+/// error or nothing at all.
+///
+/// Arithmetic is only defined between numbers, with two exceptions for dates.  Adding or
+/// subtracting a number moves a date by that many days, where a fraction of a day turns a date
+/// into a date and time.  Subtracting one date from another gives the number of days between
+/// them.  Otherwise the left operand is returned unchanged, so text, booleans and empty cells are
+/// left as is, and a number is unchanged by an operand that is not a number.
+///
+/// Money can be added to or subtracted from money in the same currency, multiplied or divided by
+/// a number, and divided by money in the same currency to give a number.  Results are rounded to
+/// the minor units of the currency, and mixing currencies gives `CellError::Value`.
+///
+/// Errors propagate, so arithmetic with an error gives that error, and dividing a number by zero
+/// gives `CellError::DivZero`.
 ///
 /// # Examples
 ///
@@ -48,20 +374,33 @@ pub const DIV0: &str = "#DIV/0";
 /// assert_eq!(number3.compare_value(&Decimal::new(123456, 3)), Some(Ordering::Greater));
 /// assert_eq!(number3.equal_value(&Decimal::new(139456, 3)), true);
 /// ```
-#[derive(Clone, Debug, PartialEq)]
+#[derive(Clone, Debug, Default, PartialEq)]
 pub enum Cell {
     /// A cell containing textual data, stored as a `String`.
     Text(String),
     /// A cell containing a numerical value, stored as a `Decimal` for precision.
     Number(Decimal),
-}
-
-impl Default for Cell {
-
-    fn default() -> Self {
-        Cell::Text("".to_string())
-    }
-
+    /// A cell containing a numerical value parsed from text like `(1,234.56)`, `$12` or `12.5%`,
+    /// along with its presentation.
+    ///
+    /// Text like `$12` or `12%` used to be parsed as `Cell::Number`.  Code that matches
+    /// `Cell::Number(_)` does not see these numbers, so use `Cell::is_number` and
+    /// `Cell::to_decimal`, which treat both variants alike, rather than matching on the variant.
+    Formatted(Decimal, NumberFormat),
+    /// A cell containing an amount of money in an ISO 4217 currency, like `USD 1234.50`.  Use
+    /// `Cell::money` to round the amount to the minor units of the currency.
+    Money(Decimal, Currency),
+    /// A cell containing `true` or `false`.
+    Bool(bool),
+    /// A cell containing a calendar date, like `2024-01-31`.
+    Date(NaiveDate),
+    /// A cell containing a date and time without a time zone, like `2024-01-31T09:30:00`.
+    DateTime(NaiveDateTime),
+    /// A cell holding an error, like the result of dividing by zero.
+    Error(CellError),
+    /// A cell without a value, like an empty csv field or a json `null`.
+    #[default]
+    Empty,
 }
 
 impl Serialize for Cell {
@@ -76,6 +415,8 @@ impl Serialize for Cell {
 
 }
 
+/// Deserializes a cell from any value.  A json number is read as a float by `serde_json`, so it
+/// can lose digits.  `Table::try_from` and `Table::from_json_with` keep every digit.
 impl<'de> Deserialize<'de> for Cell {
 
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
@@ -83,43 +424,293 @@ impl<'de> Deserialize<'de> for Cell {
         D: Deserializer<'de>,
     {
         let val = Value::deserialize(deserializer)?;
-        let str = match val {
-            Value::String(s) => s,
-            Value::Number(n) => n.to_string(),
-            Value::Bool(b) => b.to_string(),
-            Value::Null => String::new(),
-            Value::Array(a) => serde_json::to_string(&a).unwrap_or_default(),
-            Value::Object(o) => serde_json::to_string(&o).unwrap_or_default(),
-        };
-        Ok(Cell::from(str))
+        cell_from_value(val, &ParseOptions::default(), 0).map_err(de::Error::custom)
     }
 
 }
 
-impl ToString for Cell {
+impl fmt::Display for Cell {
 
-    fn to_string(&self) -> String {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            Cell::Text(s) => s.to_string(),
-            Cell::Number(n) => n.to_string(),
+            Cell::Text(s) => write!(f, "{}", s),
+            Cell::Number(n) => write!(f, "{}", n),
+            Cell::Formatted(n, format) => write!(f, "{}", format.format(n)),
+            Cell::Money(n, currency) => write!(f, "{} {}", currency, currency.round(*n)),
+            Cell::Bool(b) => write!(f, "{}", b),
+            Cell::Date(d) => write!(f, "{}", d.format(DATE_FORMAT)),
+            Cell::DateTime(dt) => write!(f, "{}", dt.format(DATETIME_FORMAT)),
+            Cell::Error(e) => write!(f, "{}", e),
+            Cell::Empty => Ok(()),
         }
     }
 
 }
 
+/// Converts a json value to a cell for the specified column, parsing strings using the options.
+pub(crate) fn cell_from_value(val: Value, options: &ParseOptions, col: usize) -> Result<Cell, String> {
+    let str = match val {
+        Value::String(s) => return cell_from_field(&s, options, col),
+        Value::Number(n) => return cell_from_number(&n.to_string(), options, col),
+        Value::Bool(b) => return cell_from_field(&b.to_string(), options, col),
+        Value::Null => return Ok(Cell::Empty),
+        Value::Array(a) => serde_json::to_string(&a).unwrap_or_default(),
+        // tagged cell like {"t":"n","v":"1.20"}
+        Value::Object(o) if o.len() == 2 => match (o.get("t").and_then(Value::as_str), o.get("v").and_then(Value::as_str)) {
+            (Some("s"), Some(v)) => return Ok(Cell::Text(v.to_string())),
+            (Some("b"), Some(v)) => {
+                return match parse_typed(v) {
+                    Some(cell @ Cell::Bool(_)) => Ok(cell),
+                    _ => Err(format!("Tagged value {} is not a boolean", v)),
+                };
+            }
+            (Some("d"), Some(v)) => {
+                return match parse_typed(v) {
+                    Some(cell @ (Cell::Date(_) | Cell::DateTime(_))) => Ok(cell),
+                    _ => Err(format!("Tagged value {} is not a date", v)),
+                };
+            }
+            (Some("e"), Some(v)) => {
+                return CellError::from_code(v).map(Cell::Error).ok_or_else(|| format!("Tagged value {} is not an error", v));
+            }
+            (Some("m"), Some(v)) => {
+                return match parse_money(v, options).or_else(|| parse_money(v, &ParseOptions::default())) {
+                    Some(Ok(cell)) => Ok(cell),
+                    _ => Err(format!("Tagged value {} is not money", v)),
+                };
+            }
+            (Some("z"), Some(_)) => return Ok(Cell::Empty),
+            (Some("n"), Some(v)) => {
+                // numbers are written with a decimal point, unless formatted
+                return match [options, &ParseOptions::default()].iter().find_map(|opts| Cell::try_parse_as(v, opts, Inference::AlwaysNumber).ok()) {
+                    Some(cell) if cell.is_number() => Ok(cell),
+                    _ => Err(format!("Tagged value {} is not a number", v)),
+                };
+            }
+            _ => serde_json::to_string(&o).unwrap_or_default(),
+        },
+        Value::Object(o) => serde_json::to_string(&o).unwrap_or_default(),
+    };
+    Ok(Cell::from(str))
+}
+
+/// Converts a raw json value to a cell for the specified column, like `cell_from_value`, except a
+/// json number keeps every digit rather than being read as a float.
+pub(crate) fn cell_from_raw(raw: &RawValue, options: &ParseOptions, col: usize) -> Result<Cell, String> {
+    let json = raw.get();
+    match json.starts_with(|c: char| c == '-' || c.is_ascii_digit()) {
+        true => cell_from_number(json, options, col),
+        false => cell_from_value(serde_json::from_str(json).map_err(|e| e.to_string())?, options, col),
+    }
+}
+
+/// Converts the text of a json number to a cell for the specified column.
+fn cell_from_number(s_ref: &str, options: &ParseOptions, col: usize) -> Result<Cell, String> {
+    // json numbers always use a decimal point
+    let number_options = ParseOptions {
+        strict: options.strict,
+        inference: options.inference_for(col),
+        ..Default::default()
+    };
+    cell_from_field(s_ref, &number_options, col)
+}
+
+/// Converts an imported field to a cell for the specified column, failing on values that cannot be
+/// parsed as requested if the options are strict.
+pub(crate) fn cell_from_field(s_ref: &str, options: &ParseOptions, col: usize) -> Result<Cell, String> {
+    match Cell::try_parse_as(s_ref, options, options.inference_for(col)) {
+        Err(e) if options.strict => Err(e),
+        Err(_) => Ok(Cell::Text(s_ref.to_string())),
+        cell => cell,
+    }
+}
+
 fn cell_from_string(s_ref: &str) -> Cell {
-    // filter for number pattern like (+/-)123,456.789
-    if RE_NUMERIC_PATTERN.is_match(s_ref) {
-        // strip unecessary characters appropriate for decimal
-        let stripped = RE_STRIP_CHARS.replace_all(s_ref, "");
-        if let Ok(d) = Decimal::from_str(&stripped) {
-            return Cell::Number(d);
+    Cell::parse(s_ref, &ParseOptions::default())
+}
+
+/// Parses an amount with an ISO 4217 currency code before or after it, like `USD 1,234.50` or
+/// `1.234,50 EUR`, or returns `None` if the text is not money.  The amount is rounded to the
+/// minor units of the currency.
+fn parse_money(s_ref: &str, options: &ParseOptions) -> Option<Result<Cell, String>> {
+    let (code, amount) = match s_ref.split_once(' ') {
+        Some((code, amount)) if Currency::from_code(code).is_some() => (code, amount),
+        _ => s_ref.rsplit_once(' ').map(|(amount, code)| (code, amount))?,
+    };
+    let currency = Currency::from_code(code)?;
+    Some(parse_number(amount.trim(), options)?.map(|(d, _)| Cell::money(d, currency)))
+}
+
+/// The currency of a number whose currency symbol is an ISO 4217 code, like `CHF` in `CHF 12.50`.
+fn format_currency(format: &NumberFormat) -> Option<Currency> {
+    match format.percent {
+        true => None,
+        false => Currency::from_code(format.prefix.trim_matches(SPACES)).or_else(|| Currency::from_code(format.suffix.trim_matches(SPACES))),
+    }
+}
+
+/// Parses `true` or `false` in any case, an ISO 8601 date like `2024-01-31`, an ISO 8601 date
+/// and time like `2024-01-31T09:30:00` or an Excel error code like `#DIV/0!`.
+fn parse_typed(s_ref: &str) -> Option<Cell> {
+    if let Some(e) = CellError::from_code(s_ref) {
+        return Some(Cell::Error(e));
+    }
+    if s_ref.eq_ignore_ascii_case("true") {
+        return Some(Cell::Bool(true));
+    }
+    if s_ref.eq_ignore_ascii_case("false") {
+        return Some(Cell::Bool(false));
+    }
+    // only four digit years separated by hyphens, so numbers like 20240131 are not dates
+    let date = s_ref.get(..10)?;
+    if !date.bytes().enumerate().all(|(i, b)| if i == 4 || i == 7 { b == b'-' } else { b.is_ascii_digit() }) {
+        return None;
+    }
+    match s_ref.len() {
+        10 => NaiveDate::parse_from_str(s_ref, DATE_FORMAT).ok().map(Cell::Date),
+        _ => DATETIME_PARSE_FORMATS.iter()
+            .find_map(|format| NaiveDateTime::parse_from_str(s_ref, format).ok())
+            .map(Cell::DateTime),
+    }
+}
+
+const SPACES: [char; 3] = [' ', '\u{a0}', '\u{202f}'];
+
+/// Parses a number with an optional presentation like `(1,234.56)`, `1,234.56-`, `-$1,234.56`,
+/// `1.234,56 €` or `12.5%`.
+///
+/// The presentation is only returned when it differs from a plain number.  Returns `None` when the
+/// text is not a number, or an error when the number cannot be held exactly by a `Decimal`.
+fn parse_number(s_ref: &str, options: &ParseOptions) -> Option<Result<(Decimal, Option<NumberFormat>), String>> {
+    let mut format = NumberFormat {
+        decimal_separator: options.decimal_separator,
+        ..Default::default()
+    };
+    let mut body = s_ref;
+    // accounting negatives
+    let mut negative = false;
+    if let Some(inner) = body.strip_prefix('(').and_then(|b| b.strip_suffix(')')) {
+        negative = true;
+        format.negative = NegativeStyle::Parentheses;
+        body = inner;
+    } else if let Some(inner) = body.strip_suffix('-') {
+        negative = true;
+        format.negative = NegativeStyle::TrailingMinus;
+        body = inner;
+    }
+    let starts_with_currency = |b: &str| options.currency_symbols.iter().any(|sym| !sym.is_empty() && b.starts_with(sym.as_str()));
+    // sign before a currency symbol, like -$1.00
+    let mut sign = "";
+    if !negative {
+        for prefix in ["-", "+"] {
+            if let Some(rest) = body.strip_prefix(prefix).filter(|rest| starts_with_currency(rest)) {
+                sign = prefix;
+                body = rest;
+            }
         }
     }
-    // otherwise return text
-    Cell::Text(s_ref.to_string())
+    // currency symbol before the number
+    if let Some(sym) = options.currency_symbols.iter().find(|sym| !sym.is_empty() && body.starts_with(sym.as_str())) {
+        let rest = body[sym.len()..].trim_start_matches(SPACES);
+        format.prefix = body[..body.len() - rest.len()].to_string();
+        body = rest;
+    }
+    // percent sign or currency symbol after the number
+    if let Some(inner) = body.strip_suffix('%') {
+        let rest = inner.trim_end_matches(SPACES);
+        format.percent = true;
+        format.suffix = body[rest.len()..].to_string();
+        body = rest;
+    } else if format.prefix.is_empty() {
+        if let Some(sym) = options.currency_symbols.iter().find(|sym| !sym.is_empty() && body.ends_with(sym.as_str())) {
+            let rest = body[..body.len() - sym.len()].trim_end_matches(SPACES);
+            format.suffix = body[rest.len()..].to_string();
+            body = rest;
+        }
+    }
+    // only one sign is allowed
+    if (negative || !sign.is_empty()) && (body.starts_with('-') || body.starts_with('+')) {
+        return None;
+    }
+    let inexact = || format!("Number {} cannot be held exactly by a decimal", s_ref);
+    let mut value = match parse_decimal(body, options).or_else(|| parse_scientific(body, options))? {
+        Some(value) => value,
+        None => return Some(Err(inexact())),
+    };
+    if negative || sign == "-" {
+        value = -value;
+    }
+    if format.percent {
+        // shift the decimal point two places to the left without changing the digits
+        value = match Decimal::try_from_i128_with_scale(value.mantissa(), value.scale() + 2) {
+            Ok(value) => value,
+            Err(_) => return Some(Err(inexact())),
+        };
+    }
+    if format.negative == NegativeStyle::Minus && format.prefix.is_empty() && format.suffix.is_empty() {
+        return Some(Ok((value, None)));
+    }
+    format.group_separator = body.chars().find(|c| options.group_separators.contains(c));
+    Some(Ok((value, Some(format))))
 }
 
+/// Parses a number in scientific notation like 1.2E+5 or -3e-4.
+///
+/// Returns `Some(None)` when the number is too large, or has too many decimal places, to be held
+/// exactly by a `Decimal`.
+fn parse_scientific(s_ref: &str, options: &ParseOptions) -> Option<Option<Decimal>> {
+    let (mantissa, exponent) = s_ref.split_once(['e', 'E'])?;
+    let exp_digits = exponent.strip_prefix(['+', '-']).unwrap_or(exponent);
+    if exp_digits.is_empty() || !exp_digits.bytes().all(|b| b.is_ascii_digit()) { return None; }
+    // digits in the mantissa are not grouped
+    if mantissa.chars().any(|c| options.group_separators.contains(&c)) { return None; }
+    let mantissa = parse_decimal(mantissa, options)?;
+    Some(mantissa.and_then(|m| match m.is_zero() {
+        true => Some(Decimal::ZERO),
+        false => Decimal::from_scientific(&format!("{}e{}", m, exponent)).ok(),
+    }))
+}
+
+/// Parses a number like (+/-)123,456.789, using the separators from the options.
+///
+/// Grouping separators are only accepted between groups of three digits.  Returns `Some(None)` when
+/// the number has too many digits to be held by a `Decimal`.
+fn parse_decimal(s_ref: &str, options: &ParseOptions) -> Option<Option<Decimal>> {
+    let (sign, unsigned) = match s_ref.strip_prefix('-') {
+        Some(rest) => ("-", rest),
+        None => ("", s_ref.strip_prefix('+').unwrap_or(s_ref)),
+    };
+    let is_digits = |part: &str| part.bytes().all(|b| b.is_ascii_digit());
+    let (int_part, frac_part) = match unsigned.split_once(options.decimal_separator) {
+        Some((int_part, frac_part)) => (int_part, Some(frac_part)),
+        None => (unsigned, None),
+    };
+    // fractional part requires at least one digit
+    if let Some(frac_part) = frac_part {
+        if frac_part.is_empty() || !is_digits(frac_part) { return None; }
+    }
+    let int_digits = match is_digits(int_part) {
+        true => int_part.to_string(),
+        false => {
+            // grouped integer part like 123,456,789
+            let mut groups = int_part.split(|c| options.group_separators.contains(&c));
+            let first = groups.next().unwrap_or_default();
+            if first.is_empty() || first.len() > 3 || !is_digits(first) { return None; }
+            let mut digits = first.to_string();
+            for group in groups {
+                if group.len() != 3 || !is_digits(group) { return None; }
+                digits.push_str(group);
+            }
+            digits
+        }
+    };
+    let normalized = match frac_part {
+        Some(frac_part) => format!("{}{}.{}", sign, int_digits, frac_part),
+        None if int_digits.is_empty() => return None,
+        None => format!("{}{}", sign, int_digits),
+    };
+    Some(Decimal::from_str(&normalized).ok())
+}
 
 impl From<String> for Cell {
 
@@ -174,8 +765,8 @@ impl TryFrom<Cell> for Decimal {
 
     fn try_from(cell: Cell) -> Result<Self, Self::Error> {
         match cell {
-            Cell::Text(_) => Err("Cell is not a number".to_string()),
-            Cell::Number(d) => Ok(d),
+            Cell::Number(d) | Cell::Formatted(d, _) | Cell::Money(d, _) => Ok(d),
+            _ => Err("Cell is not a number".to_string()),
         }
     }
 
@@ -185,12 +776,10 @@ impl Add<&Cell> for &Cell {
 
     type Output = Cell;
 
+    /// Adds the cells, or moves a date forward.  See `Cell::checked_add`, except an error is
+    /// returned as `Cell::Error` rather than panicking on overflow.
     fn add(self, other: &Cell) -> Cell {
-        if self.is_number() && other.is_number() {
-            Cell::Number(self.to_decimal().unwrap() + other.to_decimal().unwrap())
-        } else {
-            self.clone()
-        }
+        self.checked_add(other).unwrap_or_else(Cell::Error)
     }
 
 }
@@ -199,12 +788,10 @@ impl Sub<&Cell> for &Cell {
 
     type Output = Cell;
 
+    /// Subtracts the cells, or moves a date back.  See `Cell::checked_sub`, except an error is
+    /// returned as `Cell::Error` rather than panicking on overflow.
     fn sub(self, other: &Cell) -> Cell {
-        if self.is_number() && other.is_number() {
-            Cell::Number(self.to_decimal().unwrap() - other.to_decimal().unwrap())
-        } else {
-            self.clone()
-        }
+        self.checked_sub(other).unwrap_or_else(Cell::Error)
     }
 
 }
@@ -213,12 +800,10 @@ impl Mul<&Cell> for &Cell {
 
     type Output = Cell;
 
+    /// Multiplies the cells.  See `Cell::checked_mul`, except an error is returned as
+    /// `Cell::Error` rather than panicking on overflow.
     fn mul(self, other: &Cell) -> Cell {
-        if self.is_number() && other.is_number() {
-            Cell::Number(self.to_decimal().unwrap() * other.to_decimal().unwrap())
-        } else {
-            self.clone()
-        }
+        self.checked_mul(other).unwrap_or_else(Cell::Error)
     }
 
 }
@@ -227,33 +812,258 @@ impl Div<&Cell> for &Cell {
     
     type Output = Cell;
 
+    /// Divides the cells.  See `Cell::checked_div`, except an error is returned as `Cell::Error`
+    /// rather than panicking on overflow.
     fn div(self, other: &Cell) -> Cell {
-        if self.is_number() && other.is_number() {
-            let other_val = other.to_decimal().unwrap();
-            if other_val.is_zero() {
-                return Cell::from(DIV0);
-            }
-            Cell::Number(self.to_decimal().unwrap() / other_val)
-        } else {
-            self.clone()
-        }
+        self.checked_div(other).unwrap_or_else(Cell::Error)
     }
 
 }
 
 impl Cell {
 
+    /// Parses text into a cell using the specified options.
+    ///
+    /// Text that is recognised as a number becomes `Cell::Number`, or `Cell::Formatted` when the
+    /// number has a presentation like `(1.00)`, `$1.00` or `1%`.  A number with an ISO 4217
+    /// currency code, like `USD 1,234.50` or `1,234.50 EUR`, becomes `Cell::Money`, rounded to the
+    /// minor units of the currency.  `true` and `false` in any case
+    /// become `Cell::Bool`, ISO 8601 dates like `2024-01-31` become `Cell::Date` and ISO 8601
+    /// dates and times like `2024-01-31T09:30:00` become `Cell::DateTime`.  Excel error codes like
+    /// `#DIV/0!` become `Cell::Error` and empty text becomes `Cell::Empty`.  Otherwise the text
+    /// becomes `Cell::Text`.
+    ///
+    /// A number that cannot be held exactly by a `Decimal`, like `1e29` or `1e-29`, is kept as
+    /// `Cell::Text`.  Use `Cell::try_parse` to treat this as an error.
+    pub fn parse(s_ref: &str, options: &ParseOptions) -> Cell {
+        Cell::try_parse(s_ref, options).unwrap_or_else(|_| Cell::Text(s_ref.to_string()))
+    }
+
+    /// Parses text into a cell using the specified options.
+    ///
+    /// Same as `Cell::parse`, except a number that cannot be held exactly by a `Decimal` is an
+    /// error.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_decimal::Decimal;
+    /// use tablefi::{Cell, ParseOptions};
+    ///
+    /// let options = ParseOptions::default();
+    /// assert_eq!(Cell::try_parse("1.2E+5", &options), Ok(Cell::Number(Decimal::from(120000))));
+    /// assert_eq!(Cell::try_parse("3e-4", &options), Ok(Cell::Number(Decimal::new(3, 4))));
+    /// assert!(Cell::try_parse("1e-29", &options).is_err());
+    /// assert!(Cell::parse("1e-29", &options).is_text());
+    /// ```
+    pub fn try_parse(s_ref: &str, options: &ParseOptions) -> Result<Cell, String> {
+        Cell::try_parse_as(s_ref, options, options.inference)
+    }
+
+    /// Parses text into a cell using the specified options and inference.
+    pub(crate) fn try_parse_as(s_ref: &str, options: &ParseOptions, inference: Inference) -> Result<Cell, String> {
+        if s_ref.is_empty() {
+            return Ok(Cell::Empty);
+        }
+        if inference == Inference::AlwaysText {
+            return Ok(Cell::Text(s_ref.to_string()));
+        }
+        match parse_number(s_ref, options) {
+            Some(Ok((d, None))) => Ok(Cell::Number(d)),
+            Some(Ok((d, Some(format)))) => Ok(match format_currency(&format) {
+                Some(currency) => Cell::money(d, currency),
+                None => Cell::Formatted(d, format),
+            }),
+            Some(Err(e)) => Err(e),
+            None => match parse_money(s_ref, options) {
+                Some(result) => result,
+                None if inference == Inference::AlwaysNumber => Err(format!("Value {} is not a number", s_ref)),
+                None => Ok(parse_typed(s_ref).unwrap_or_else(|| Cell::Text(s_ref.to_string()))),
+            },
+        }
+    }
+
+    /// Whether this cell is aligned and written as a number, which is a number or an amount of
+    /// money.
+    pub fn is_numeric(&self) -> bool {
+        self.is_number() || self.is_money()
+    }
+
     /// Whether this cell contains textual data.
     pub fn is_text(&self) -> bool {
-        match self {
-            Cell::Text(_) => true,
-            Cell::Number(_) => false,
-        }
+        matches!(self, Cell::Text(_))
     }
 
     /// Whether this cell contains a numerical value.
     pub fn is_number(&self) -> bool {
-        !self.is_text()
+        matches!(self, Cell::Number(_) | Cell::Formatted(_, _))
+    }
+
+    /// Whether this cell contains an amount of money.  Money is not a number, since it cannot be
+    /// mixed with amounts in other currencies.
+    pub fn is_money(&self) -> bool {
+        matches!(self, Cell::Money(_, _))
+    }
+
+    /// The currency of a money cell.
+    pub fn currency(&self) -> Option<Currency> {
+        match self {
+            Cell::Money(_, currency) => Some(*currency),
+            _ => None,
+        }
+    }
+
+    /// Returns a money cell with the amount rounded to the minor units of the currency, like
+    /// `USD 1.50` for `1.499` dollars or `JPY 2` for `1.5` yen.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_decimal::Decimal;
+    /// use tablefi::{Cell, CellError, Currency};
+    ///
+    /// let usd = Currency::from_code("USD").unwrap();
+    /// let eur = Currency::from_code("EUR").unwrap();
+    /// let price = Cell::money(Decimal::new(1999, 3), usd);
+    /// assert_eq!(price.to_string(), "USD 2.00");
+    /// assert_eq!((&price * &Cell::from("0.333")).to_string(), "USD 0.67");
+    /// assert_eq!(&price + &Cell::money(Decimal::ONE, eur), Cell::Error(CellError::Value));
+    /// assert_eq!(Cell::from("1,234.5 USD"), Cell::money(Decimal::new(12345, 1), usd));
+    /// ```
+    pub fn money(value: Decimal, currency: Currency) -> Cell {
+        Cell::Money(currency.round(value), currency)
+    }
+
+    /// Whether this cell contains `true` or `false`.
+    pub fn is_bool(&self) -> bool {
+        matches!(self, Cell::Bool(_))
+    }
+
+    /// Whether this cell contains a date, with or without a time.
+    pub fn is_date(&self) -> bool {
+        matches!(self, Cell::Date(_) | Cell::DateTime(_))
+    }
+
+    /// Whether this cell holds an error.
+    pub fn is_error(&self) -> bool {
+        matches!(self, Cell::Error(_))
+    }
+
+    /// The error held by the cell, if any.
+    pub fn error(&self) -> Option<CellError> {
+        match self {
+            Cell::Error(e) => Some(*e),
+            _ => None,
+        }
+    }
+
+    /// Whether this cell has no value, either `Cell::Empty` or empty text.
+    pub fn is_empty(&self) -> bool {
+        match self {
+            Cell::Empty => true,
+            Cell::Text(s) => s.is_empty(),
+            _ => false,
+        }
+    }
+
+    /// The presentation of the number, if parsed from text like `(1.00)`, `$1.00` or `1%`.
+    pub fn format(&self) -> Option<&NumberFormat> {
+        match self {
+            Cell::Formatted(_, format) => Some(format),
+            _ => None,
+        }
+    }
+
+    /// Returns a number cell with the new value, keeping the presentation of this cell.
+    fn with_decimal(&self, value: Decimal) -> Cell {
+        match self {
+            Cell::Formatted(_, format) => Cell::Formatted(value, format.clone()),
+            _ => Cell::Number(value),
+        }
+    }
+
+    /// Applies a checked operation to the amounts of both cells, or returns `None` if either cell
+    /// is not a number or money.  The currency of the result is given by `currency`, and money
+    /// is rounded to the minor units of its currency.
+    fn checked_number(&self, other: &Cell, op: fn(Decimal, Decimal) -> Option<Decimal>, currency: CurrencyRule) -> Option<Result<Cell, CellError>> {
+        let (d1, d2) = (self.to_decimal()?, other.to_decimal()?);
+        Some(currency(self.currency(), other.currency()).and_then(|currency| {
+            let value = op(d1, d2).ok_or(CellError::Overflow)?;
+            Ok(currency.map_or_else(|| self.with_decimal(value), |currency| Cell::money(value, currency)))
+        }))
+    }
+
+    /// The currency of a sum or difference, which is `CellError::Value` for different currencies
+    /// or money with a number, since the number has no currency.
+    fn sum_currency(c1: Option<Currency>, c2: Option<Currency>) -> Result<Option<Currency>, CellError> {
+        match (c1, c2) {
+            (c1, c2) if c1 != c2 => Err(CellError::Value),
+            (c1, _) => Ok(c1),
+        }
+    }
+
+    /// A value to add to or subtract from this cell, which is an amount in the currency of money.
+    fn value_cell(&self, value: Decimal) -> Cell {
+        match self.currency() {
+            Some(currency) => Cell::Money(value, currency),
+            None => Cell::Number(value),
+        }
+    }
+
+    /// The currency of a product, which is `CellError::Value` when both amounts are money.
+    fn product_currency(c1: Option<Currency>, c2: Option<Currency>) -> Result<Option<Currency>, CellError> {
+        match (c1, c2) {
+            (Some(_), Some(_)) => Err(CellError::Value),
+            (c1, c2) => Ok(c1.or(c2)),
+        }
+    }
+
+    /// The currency of a quotient, where money divided by money in the same currency is a
+    /// number, and dividing by money is otherwise `CellError::Value`.
+    fn quotient_currency(c1: Option<Currency>, c2: Option<Currency>) -> Result<Option<Currency>, CellError> {
+        match (c1, c2) {
+            (Some(c1), Some(c2)) if c1 == c2 => Ok(None),
+            (_, Some(_)) => Err(CellError::Value),
+            (c1, None) => Ok(c1),
+        }
+    }
+
+    /// The number of days to move a date by, which is the value of a number cell.
+    fn days(&self) -> Option<Decimal> {
+        self.to_decimal().filter(|_| self.is_number())
+    }
+
+    /// Returns the date and time of a date cell, where a date is at midnight.
+    fn to_datetime(&self) -> Option<NaiveDateTime> {
+        match self {
+            Cell::Date(d) => Some(d.and_time(NaiveTime::MIN)),
+            Cell::DateTime(dt) => Some(*dt),
+            _ => None,
+        }
+    }
+
+    /// Returns a date cell moved by a number of days, or `None` if this cell is not a date or the
+    /// new date is out of range.  A fraction of a day turns a date into a date and time.
+    fn add_days(&self, days: Decimal) -> Option<Cell> {
+        match self {
+            Cell::Date(d) if days.fract().is_zero() => d.checked_add_signed(TimeDelta::try_days(days.to_i64()?)?).map(Cell::Date),
+            Cell::Date(d) => Cell::DateTime(d.and_time(NaiveTime::MIN)).add_days(days),
+            Cell::DateTime(dt) => {
+                let milliseconds = days.checked_mul(Decimal::from(MILLISECONDS_PER_DAY))?.round().to_i64()?;
+                dt.checked_add_signed(TimeDelta::try_milliseconds(milliseconds)?).map(Cell::DateTime)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the number of days from the date of another cell to the date of this cell, or
+    /// `None` if either cell is not a date.
+    fn days_since(&self, other: &Cell) -> Option<Decimal> {
+        match (self, other) {
+            (Cell::Date(d1), Cell::Date(d2)) => Some(Decimal::from((*d1 - *d2).num_days())),
+            _ => {
+                let milliseconds = (self.to_datetime()? - other.to_datetime()?).num_milliseconds();
+                Some((Decimal::from(milliseconds) / Decimal::from(MILLISECONDS_PER_DAY)).normalize())
+            }
+        }
     }
 
     /// Converts the cell to a Decimal.
@@ -266,47 +1076,144 @@ impl Cell {
         *self = new_value.clone();
     }
 
-    /// Adds value.
-    pub fn add_value(&mut self, value: Decimal) {
-        if let Cell::Number(d) = self {
-            *d += value;
+    /// Adds another cell, or moves a date forward by the number of days in the other cell.
+    ///
+    /// Returns the error if either cell holds an error, `CellError::Value` for money in different
+    /// currencies or money with a number, or `CellError::Overflow` if the sum is too large for a
+    /// `Decimal`.  Otherwise a cell that cannot be added to is returned unchanged.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_decimal::Decimal;
+    /// use tablefi::{Cell, CellError};
+    ///
+    /// assert_eq!(Cell::from("1.50").checked_add(&Cell::from("2")), Ok(Cell::from("3.50")));
+    /// assert_eq!(Cell::from(Decimal::MAX).checked_add(&Cell::from("1")), Err(CellError::Overflow));
+    ///
+    /// // the operators return the error as a cell
+    /// assert_eq!(&Cell::from(Decimal::MAX) + &Cell::from("1"), Cell::Error(CellError::Overflow));
+    /// ```
+    pub fn checked_add(&self, other: &Cell) -> Result<Cell, CellError> {
+        if let Some(e) = self.error().or(other.error()) {
+            return Err(e);
+        }
+        match self.checked_number(other, Decimal::checked_add, Cell::sum_currency) {
+            Some(result) => result,
+            None => Ok(other.days().and_then(|days| self.add_days(days)).unwrap_or_else(|| self.clone())),
         }
     }
 
-    /// Subtracts value.
-    pub fn sub_value(&mut self, value: Decimal) {
-        if let Cell::Number(d) = self {
-            *d -= value;
+    /// Subtracts another cell, moves a date back by the number of days in the other cell, or
+    /// returns the number of days between two dates.
+    ///
+    /// Returns the error if either cell holds an error, `CellError::Value` for money in different
+    /// currencies or money with a number, or `CellError::Overflow` if the difference is too large
+    /// for a `Decimal`.
+    pub fn checked_sub(&self, other: &Cell) -> Result<Cell, CellError> {
+        if let Some(e) = self.error().or(other.error()) {
+            return Err(e);
+        }
+        match self.checked_number(other, Decimal::checked_sub, Cell::sum_currency) {
+            Some(result) => result,
+            None => Ok(match self.days_since(other) {
+                Some(days) => Cell::Number(days),
+                None => other.days().and_then(|days| self.add_days(-days)).unwrap_or_else(|| self.clone()),
+            }),
         }
     }
 
-    /// Multiplies value.
-    pub fn mul_value(&mut self, value: Decimal) {
-        if let Cell::Number(d) = self {
-            *d *= value;
+    /// Multiplies by another cell.
+    ///
+    /// Returns the error if either cell holds an error, `CellError::Value` if both cells are
+    /// money, or `CellError::Overflow` if the product is too large for a `Decimal`.
+    pub fn checked_mul(&self, other: &Cell) -> Result<Cell, CellError> {
+        if let Some(e) = self.error().or(other.error()) {
+            return Err(e);
         }
+        self.checked_number(other, Decimal::checked_mul, Cell::product_currency).unwrap_or_else(|| Ok(self.clone()))
     }
 
-    /// Divides value.
-    pub fn div_value(&mut self, value: Decimal) {
-        if let Cell::Number(d) = self {
-            match value.is_zero() {
-                true => *self = Cell::from(DIV0),
-                false => *d /= value,
-            }
+    /// Divides by another cell.
+    ///
+    /// Returns the error if either cell holds an error, `CellError::DivZero` if the other cell is
+    /// zero, `CellError::Value` if the other cell is money in a different currency or this cell
+    /// is not money, or `CellError::Overflow` if the quotient is too large for a `Decimal`.
+    pub fn checked_div(&self, other: &Cell) -> Result<Cell, CellError> {
+        if let Some(e) = self.error().or(other.error()) {
+            return Err(e);
+        }
+        if self.to_decimal().is_some() && other.to_decimal().is_some_and(|d| d.is_zero()) {
+            return Err(CellError::DivZero);
         }
+        self.checked_number(other, Decimal::checked_div, Cell::quotient_currency).unwrap_or_else(|| Ok(self.clone()))
+    }
+
+    /// Adds value, in the currency of money, or moves a date forward by value days.  A result too
+    /// large for a `Decimal` becomes `CellError::Overflow`.
+    pub fn add_value(&mut self, value: Decimal) {
+        *self = &*self + &self.value_cell(value);
+    }
+
+    /// Subtracts value, in the currency of money, or moves a date back by value days.  A result too
+    /// large for a `Decimal` becomes `CellError::Overflow`.
+    pub fn sub_value(&mut self, value: Decimal) {
+        *self = &*self - &self.value_cell(value);
+    }
+
+    /// Multiplies value.  A result too large for a `Decimal` becomes `CellError::Overflow`.
+    pub fn mul_value(&mut self, value: Decimal) {
+        *self = &*self * &Cell::Number(value);
+    }
+
+    /// Divides value.  A value of `0` becomes `CellError::DivZero`.
+    pub fn div_value(&mut self, value: Decimal) {
+        *self = &*self / &Cell::Number(value);
+    }
+
+    /// Adds value, in the currency of money, or moves a date forward by value days.
+    ///
+    /// Same as `Cell::checked_add`, leaving the cell unchanged when an error is returned.
+    pub fn checked_add_value(&mut self, value: Decimal) -> Result<(), CellError> {
+        *self = self.checked_add(&self.value_cell(value))?;
+        Ok(())
+    }
+
+    /// Subtracts value, in the currency of money, or moves a date back by value days.
+    ///
+    /// Same as `Cell::checked_sub`, leaving the cell unchanged when an error is returned.
+    pub fn checked_sub_value(&mut self, value: Decimal) -> Result<(), CellError> {
+        *self = self.checked_sub(&self.value_cell(value))?;
+        Ok(())
+    }
+
+    /// Multiplies value.
+    ///
+    /// Same as `Cell::checked_mul`, leaving the cell unchanged when an error is returned.
+    pub fn checked_mul_value(&mut self, value: Decimal) -> Result<(), CellError> {
+        *self = self.checked_mul(&Cell::Number(value))?;
+        Ok(())
+    }
+
+    /// Divides value.
+    ///
+    /// Same as `Cell::checked_div`, leaving the cell unchanged when an error is returned.
+    pub fn checked_div_value(&mut self, value: Decimal) -> Result<(), CellError> {
+        *self = self.checked_div(&Cell::Number(value))?;
+        Ok(())
     }
 
     /// Whether the value of the cell has been divided by zero.
     pub fn is_divide_by_zero(&self) -> bool {
-        self.to_string() == DIV0
+        self.error() == Some(CellError::DivZero)
     }
 
     /// Compares the value of this cell with another value.
     ///
     /// The `other_value` can be a `String`, `&str`, `Decimal`, or another `Cell`.
-    /// It returns `Some(Ordering)` if the types are comparable (Number with Number, Text with Text),
-    /// and `None` otherwise (e.g., Text with Number).
+    /// It returns `Some(Ordering)` if the types are comparable (Number with Number, Text with Text,
+    /// Bool with Bool, a date with a date and Empty with Empty), and `None` otherwise (e.g., Text
+    /// with Number, or any comparison with an error).  Booleans order `false` before `true`, and a
+    /// date without a time is compared as midnight.
     ///
     /// # Examples
     /// ```
@@ -318,13 +1225,18 @@ impl Cell {
     /// assert_eq!(Cell::from("apple").compare_value("banana"), Some(Ordering::Less));
     /// assert_eq!(Cell::from("10").compare_value("banana"), None);
     /// assert_eq!(Cell::Number(Decimal::new(5,0)).compare_value(&Decimal::new(5,0)), Some(Ordering::Equal));
+    /// assert_eq!(Cell::from("2024-01-31").compare_value("2024-02-01T09:30:00"), Some(Ordering::Less));
     /// ```
     pub fn compare_value<T: ?Sized>(&self, other_value: &T) -> Option<Ordering> where for<'r> &'r T: Into<Cell> {
         let other_cell: Cell = other_value.into();
         match (self, other_cell) {
-            (Cell::Number(n1), Cell::Number(n2)) => n1.partial_cmp(&n2),
             (Cell::Text(s1), Cell::Text(s2)) => s1.partial_cmp(&s2),
-            _ => None, // Mismatched types (Number vs Text or Text vs Number)
+            (Cell::Bool(b1), Cell::Bool(b2)) => b1.partial_cmp(&b2),
+            (Cell::Empty, Cell::Empty) => Some(Ordering::Equal),
+            (Cell::Money(d1, c1), Cell::Money(d2, c2)) => (*c1 == c2).then(|| d1.cmp(&d2)),
+            (n1, n2) if n1.is_number() && n2.is_number() => n1.to_decimal().partial_cmp(&n2.to_decimal()),
+            // Mismatched types (Number vs Text or Text vs Number) have no date and time
+            (d1, d2) => d1.to_datetime().zip(d2.to_datetime()).and_then(|(dt1, dt2)| dt1.partial_cmp(&dt2)),
         }
     }
 
@@ -338,6 +1250,7 @@ impl Cell {
 }
 
 #[cfg(test)]
+#[allow(clippy::bool_assert_comparison, clippy::clone_on_copy)]
 mod tests {
     use super::*;
 
@@ -402,6 +1315,133 @@ mod tests {
         assert!(TryInto::<Decimal>::try_into(Cell::from("-12a,456,781")).is_err());
     }
 
+    #[test]
+    fn test_parse_locale() {
+        let german = ParseOptions::german();
+        assert_eq!(Cell::parse("1.234,56", &german).to_decimal(), Some(Decimal::new(123456, 2)));
+        assert_eq!(Cell::parse("-1.234.567", &german).to_decimal(), Some(Decimal::from(-1234567)));
+        assert_eq!(Cell::parse(",5", &german).to_decimal(), Some(Decimal::new(5, 1)));
+        assert!(Cell::parse("1,234.56", &german).is_text());
+        assert!(Cell::parse("1.23,4", &german).is_text());
+        let swiss = ParseOptions::swiss();
+        assert_eq!(Cell::parse("1'234.56", &swiss).to_decimal(), Some(Decimal::new(123456, 2)));
+        assert_eq!(Cell::parse("1\u{2019}234", &swiss).to_decimal(), Some(Decimal::from(1234)));
+        let french = ParseOptions::french();
+        assert_eq!(Cell::parse("1 234,56", &french).to_decimal(), Some(Decimal::new(123456, 2)));
+        assert_eq!(Cell::parse("1\u{202f}234\u{a0}567", &french).to_decimal(), Some(Decimal::from(1234567)));
+        assert!(Cell::parse("1 234.56", &french).is_text());
+        assert!(Cell::parse("1  234", &french).is_text());
+        let ungrouped = ParseOptions { group_separators: vec![], ..Default::default() };
+        assert!(Cell::parse("1,234", &ungrouped).is_text());
+        assert_eq!(Cell::parse("1234.5", &ungrouped).to_decimal(), Some(Decimal::new(12345, 1)));
+    }
+
+    #[test]
+    fn test_parse_accounting() {
+        assert_eq!(Cell::from("(1,234.56)").to_decimal(), Some(Decimal::new(-123456, 2)));
+        assert_eq!(Cell::from("(1,234.56)").to_string(), "(1,234.56)");
+        assert_eq!(Cell::from("1234.56-").to_decimal(), Some(Decimal::new(-123456, 2)));
+        assert_eq!(Cell::from("1234.56-").to_string(), "1234.56-");
+        assert!(Cell::from("(-1234.56)").is_text());
+        assert!(Cell::from("-1234.56-").is_text());
+        assert!(Cell::from("(1234.56").is_text());
+        assert!(Cell::from("()").is_text());
+        assert!(Cell::from("-").is_text());
+        // presentation is kept after arithmetic
+        let mut cell = Cell::from("(1,234.56)");
+        cell.add_value(Decimal::from(2000));
+        assert_eq!(cell.to_string(), "765.44");
+        cell.sub_value(Decimal::from(1000000));
+        assert_eq!(cell.to_string(), "(999,234.56)");
+    }
+
+    #[test]
+    fn test_parse_currency() {
+        assert_eq!(Cell::from("$1,234.56").to_decimal(), Some(Decimal::new(123456, 2)));
+        assert_eq!(Cell::from("$1,234.56").to_string(), "$1,234.56");
+        assert_eq!(Cell::from("-$1,234.56").to_decimal(), Some(Decimal::new(-123456, 2)));
+        assert_eq!(Cell::from("-$1,234.56").to_string(), "-$1,234.56");
+        assert_eq!(Cell::from("($1,234.56)").to_decimal(), Some(Decimal::new(-123456, 2)));
+        assert_eq!(Cell::from("$(1,234.56)").to_decimal(), None);
+        assert_eq!(Cell::from("€12").to_decimal(), Some(Decimal::from(12)));
+        assert_eq!(Cell::from("CHF 12.50").to_string(), "CHF 12.50");
+        assert!(Cell::from("CHF 12.50").is_money());
+        assert_eq!(Cell::from("12 €").to_decimal(), Some(Decimal::from(12)));
+        assert_eq!(Cell::parse("1.234,56\u{a0}€", &ParseOptions::german()).to_string(), "1.234,56\u{a0}€");
+        assert!(Cell::from("$").is_text());
+        assert!(Cell::from("$$12").is_text());
+        assert!(Cell::from("--$12").is_text());
+        let options = ParseOptions { currency_symbols: vec![], ..Default::default() };
+        assert!(Cell::parse("$12", &options).is_text());
+        // arithmetic with plain numbers
+        let total = &Cell::from("$1,000.00") + &Cell::from("234.56");
+        assert_eq!(total.to_string(), "$1,234.56");
+        assert!(total.equal_value("1234.56"));
+    }
+
+    #[test]
+    fn test_parse_percent() {
+        assert_eq!(Cell::from("12.5%").to_decimal(), Some(Decimal::new(125, 3)));
+        assert_eq!(Cell::from("12.5%").to_string(), "12.5%");
+        assert_eq!(Cell::from("-3%").to_decimal(), Some(Decimal::new(-3, 2)));
+        assert_eq!(Cell::from("(3 %)").to_string(), "(3 %)");
+        assert_eq!(Cell::parse("12,5 %", &ParseOptions::french()).to_decimal(), Some(Decimal::new(125, 3)));
+        assert!(Cell::from("%").is_text());
+        let mut cell = Cell::from("50%");
+        cell.mul_value(Decimal::new(5, 1));
+        assert_eq!(cell.to_decimal(), Some(Decimal::new(25, 2)));
+        assert_eq!(cell.to_string(), "25.0%");
+        // too large to present as a percentage
+        let mut cell = Cell::from("1%");
+        cell.add_value(Decimal::from_str("7e28").unwrap());
+        assert_eq!(cell.to_string(), cell.to_decimal().unwrap().to_string());
+    }
+
+    #[test]
+    fn test_parse_scientific() {
+        let options = ParseOptions::default();
+        assert_eq!(Cell::from("1.2E+5").to_decimal(), Some(Decimal::from(120000)));
+        assert_eq!(Cell::from("1.2e5").to_decimal(), Some(Decimal::from(120000)));
+        assert_eq!(Cell::from("3e-4").to_decimal(), Some(Decimal::new(3, 4)));
+        assert_eq!(Cell::from("-2.5E-3").to_decimal(), Some(Decimal::new(-25, 4)));
+        assert_eq!(Cell::from("0e99").to_decimal(), Some(Decimal::ZERO));
+        assert_eq!(Cell::from("1.5E2%").to_decimal(), Some(Decimal::new(15, 1)));
+        assert_eq!(Cell::parse("1,5E+3", &ParseOptions::german()).to_decimal(), Some(Decimal::from(1500)));
+        assert!(Cell::from("1,000e3").is_text());
+        assert!(Cell::from("1e").is_text());
+        assert!(Cell::from("e5").is_text());
+        assert!(Cell::from("1e+-5").is_text());
+        assert!(Cell::from("1e5.5").is_text());
+        // values that cannot be held exactly
+        assert!(Cell::try_parse("1e29", &options).unwrap_err().contains("1e29"));
+        assert!(Cell::try_parse("1.5e-28", &options).is_err());
+        assert!(Cell::try_parse("1e99999999999999999999", &options).is_err());
+        assert!(Cell::try_parse("123456789012345678901234567890", &options).is_err());
+        assert!(Cell::from("1e29").is_text());
+        assert_eq!(Cell::try_parse("abc", &options), Ok(Cell::from("abc")));
+    }
+
+    #[test]
+    fn test_inference() {
+        let text = ParseOptions { inference: Inference::AlwaysText, ..Default::default() };
+        assert_eq!(Cell::parse("00123", &text), Cell::Text("00123".to_string()));
+        assert_eq!(Cell::parse("1,234", &text), Cell::Text("1,234".to_string()));
+        let number = ParseOptions { inference: Inference::AlwaysNumber, ..Default::default() };
+        assert_eq!(Cell::try_parse("1,234", &number), Ok(Cell::Number(Decimal::from(1234))));
+        assert_eq!(Cell::try_parse("", &number), Ok(Cell::default()));
+        assert!(Cell::try_parse("abc", &number).is_err());
+        assert!(Cell::parse("abc", &number).is_text());
+        let mut columns = ParseOptions::default();
+        columns.column_inference.insert(1, Inference::AlwaysText);
+        assert_eq!(columns.inference_for(0), Inference::Auto);
+        assert_eq!(columns.inference_for(1), Inference::AlwaysText);
+        assert_eq!(cell_from_field("00123", &columns, 0), Ok(Cell::Number(Decimal::from(123))));
+        assert_eq!(cell_from_field("00123", &columns, 1), Ok(Cell::Text("00123".to_string())));
+        assert_eq!(cell_from_value(Value::from(123), &columns, 1), Ok(Cell::Text("123".to_string())));
+        let strict = ParseOptions { strict: true, ..number };
+        assert!(cell_from_field("abc", &strict, 0).is_err());
+    }
+
     #[test]
     fn test_json() {
         let cell: Cell = serde_json::from_str(r#""hello""#).unwrap();
@@ -411,13 +1451,66 @@ mod tests {
         let cell: Cell = serde_json::from_str(r#"12345.67"#).unwrap();
         assert_eq!(cell.to_decimal(), Some(Decimal::new(1234567, 2)));
         let cell: Cell = serde_json::from_str(r#"true"#).unwrap();
+        assert_eq!(cell, Cell::Bool(true));
         assert_eq!(cell.to_string(), "true");
         let cell: Cell = serde_json::from_str(r#"null"#).unwrap();
+        assert_eq!(cell, Cell::Empty);
         assert_eq!(cell.to_string(), "");
         let cell: Cell = serde_json::from_str(r#"["a"]"#).unwrap();
         assert_eq!(cell.to_string(), r#"["a"]"#);
         let cell: Cell = serde_json::from_str(r#"{"a":1}"#).unwrap();
         assert_eq!(cell.to_string(), r#"{"a":1}"#);
+        let cell: Cell = serde_json::from_str(r#"{"t":"s","v":"12"}"#).unwrap();
+        assert_eq!(cell, Cell::Text("12".to_string()));
+        let cell: Cell = serde_json::from_str(r#"{"t":"n","v":"1.20"}"#).unwrap();
+        assert_eq!(cell, Cell::Number(Decimal::new(120, 2)));
+        let cell: Cell = serde_json::from_str(r#"{"t":"x","v":"1.20"}"#).unwrap();
+        assert_eq!(cell.to_string(), r#"{"t":"x","v":"1.20"}"#);
+        assert!(serde_json::from_str::<Cell>(r#"{"t":"n","v":"abc"}"#).is_err());
+        let cell: Cell = serde_json::from_str(r#"{"t":"b","v":"false"}"#).unwrap();
+        assert_eq!(cell, Cell::Bool(false));
+        let cell: Cell = serde_json::from_str(r#"{"t":"d","v":"2024-01-31"}"#).unwrap();
+        assert_eq!(cell, Cell::Date(NaiveDate::from_ymd_opt(2024, 1, 31).unwrap()));
+        let cell: Cell = serde_json::from_str(r##"{"t":"e","v":"#REF!"}"##).unwrap();
+        assert_eq!(cell, Cell::Error(CellError::Ref));
+        assert!(serde_json::from_str::<Cell>(r#"{"t":"e","v":"x"}"#).is_err());
+        let cell: Cell = serde_json::from_str(r#"{"t":"z","v":""}"#).unwrap();
+        assert_eq!(cell, Cell::Empty);
+        assert!(serde_json::from_str::<Cell>(r#"{"t":"b","v":"1"}"#).is_err());
+        assert!(serde_json::from_str::<Cell>(r#"{"t":"d","v":"x"}"#).is_err());
+    }
+
+    #[test]
+    fn test_parse_typed() {
+        let date = NaiveDate::from_ymd_opt(2024, 1, 31).unwrap();
+        assert_eq!(Cell::from("true"), Cell::Bool(true));
+        assert_eq!(Cell::from("FALSE"), Cell::Bool(false));
+        assert_eq!(Cell::from("2024-01-31"), Cell::Date(date));
+        assert_eq!(Cell::from("2024-01-31T09:30:00"), Cell::DateTime(date.and_hms_opt(9, 30, 0).unwrap()));
+        assert_eq!(Cell::from("2024-01-31 09:30"), Cell::DateTime(date.and_hms_opt(9, 30, 0).unwrap()));
+        assert_eq!(Cell::from("2024-01-31T09:30:00.250").to_string(), "2024-01-31T09:30:00.250");
+        assert_eq!(Cell::from("2024-01-31 09:30").to_string(), "2024-01-31T09:30:00");
+        assert_eq!(Cell::from(""), Cell::Empty);
+        assert!(Cell::from("20240131").is_number());
+        assert!(Cell::from("2024-02-30").is_text());
+        assert!(Cell::from("2024-1-31").is_text());
+        assert!(Cell::from("2024-01-31Z").is_text());
+        assert!(Cell::from("yes").is_text());
+        let text = ParseOptions { inference: Inference::AlwaysText, ..Default::default() };
+        assert_eq!(Cell::parse("true", &text), Cell::Text("true".to_string()));
+        assert_eq!(Cell::parse("", &text), Cell::Empty);
+        let number = ParseOptions { inference: Inference::AlwaysNumber, ..Default::default() };
+        assert!(Cell::try_parse("2024-01-31", &number).is_err());
+    }
+
+    #[test]
+    fn test_is_empty() {
+        assert!(Cell::Empty.is_empty());
+        assert!(Cell::Text(String::new()).is_empty());
+        assert!(!Cell::Bool(false).is_empty());
+        assert!(!Cell::Empty.is_text());
+        assert!(!Cell::Empty.is_number());
+        assert_eq!(Cell::default(), Cell::Empty);
     }
 
     #[test]
@@ -481,12 +1574,177 @@ mod tests {
         assert_eq!((&number1 / &number2).to_decimal(), Some(Decimal::new(15432, 3)));
         assert!((&number1 / &Cell::from("0")).to_decimal().is_none());
         assert!((&number1 / &Cell::from("0")).is_divide_by_zero());
+        assert_eq!(&number1 / &Cell::Empty, number1);
         let mut number3 = number1.clone();
         number3.div_value(Decimal::from(8));
         assert_eq!(number3.to_decimal(), Some(Decimal::new(15432, 3)));
         number3.div_value(Decimal::from(0));
         assert!(number3.to_decimal().is_none());
         assert!(number3.is_divide_by_zero());
+        assert_eq!(number3.to_string(), "#DIV/0!");
+        // text like the error code is not an error
+        assert!(!Cell::Text("#DIV/0!".to_string()).is_divide_by_zero());
+        assert!(!Cell::from("#DIV/0").is_error());
+    }
+
+    #[test]
+    fn test_cell_error() {
+        let error = Cell::Error(CellError::NA);
+        let number = Cell::from("2");
+        assert_eq!(&number + &error, error);
+        assert_eq!(&error - &number, error);
+        assert_eq!(&Cell::from("abc") * &error, error);
+        assert_eq!(&Cell::from("2024-01-31") + &error, error);
+        assert_eq!(&error / &Cell::from("0"), error);
+        assert_eq!(&Cell::Error(CellError::Ref) + &error, Cell::Error(CellError::Ref));
+        let mut cell = error.clone();
+        cell.add_value(Decimal::ONE);
+        cell.div_value(Decimal::ZERO);
+        assert_eq!(cell, error);
+        assert_eq!(error.compare_value(&error), None);
+        for e in [CellError::DivZero, CellError::Value, CellError::Ref, CellError::NA, CellError::Overflow] {
+            assert_eq!(CellError::from_code(e.code()), Some(e));
+            assert_eq!(Cell::from(e.to_string()), Cell::Error(e));
+        }
+        assert_eq!(CellError::from_code("#NAME?"), None);
+        let text = ParseOptions { inference: Inference::AlwaysText, ..Default::default() };
+        assert!(Cell::parse("#N/A", &text).is_text());
+    }
+
+    #[test]
+    fn test_cell_overflow() {
+        let max = Cell::from(Decimal::MAX);
+        let one = Cell::from("1");
+        assert_eq!(max.checked_add(&one), Err(CellError::Overflow));
+        assert_eq!(Cell::from(Decimal::MIN).checked_sub(&one), Err(CellError::Overflow));
+        assert_eq!(max.checked_mul(&Cell::from("2")), Err(CellError::Overflow));
+        assert_eq!(max.checked_div(&Cell::from("0.1")), Err(CellError::Overflow));
+        assert_eq!(one.checked_div(&Cell::from("0")), Err(CellError::DivZero));
+        assert_eq!(one.checked_add(&Cell::Error(CellError::NA)), Err(CellError::NA));
+        assert_eq!(one.checked_add(&one), Ok(Cell::from("2")));
+        assert_eq!(Cell::from("abc").checked_div(&Cell::from("0")), Ok(Cell::from("abc")));
+        assert_eq!(Cell::from("2024-01-31").checked_add(&one), Ok(Cell::from("2024-02-01")));
+        assert_eq!(&max + &one, Cell::Error(CellError::Overflow));
+        assert_eq!(&max * &max, Cell::Error(CellError::Overflow));
+        assert_eq!((&max / &Cell::from("0.1")).to_string(), "#NUM!");
+        assert_eq!(&Cell::from("$1.00") * &Cell::from("2"), Cell::from("$2.00"));
+        let mut cell = max.clone();
+        assert_eq!(cell.checked_add_value(Decimal::ONE), Err(CellError::Overflow));
+        assert_eq!(cell, max);
+        assert_eq!(cell.checked_mul_value(Decimal::TWO), Err(CellError::Overflow));
+        assert_eq!(cell.checked_div_value(Decimal::ZERO), Err(CellError::DivZero));
+        assert_eq!(cell.checked_sub_value(Decimal::ONE), Ok(()));
+        assert_eq!(cell, Cell::from(Decimal::MAX - Decimal::ONE));
+        cell.mul_value(Decimal::TWO);
+        assert_eq!(cell, Cell::Error(CellError::Overflow));
+    }
+
+    #[test]
+    fn test_money() {
+        let usd = Currency::from_code("USD").unwrap();
+        let jpy = Currency::from_code("JPY").unwrap();
+        let bhd = Currency::from_code("BHD").unwrap();
+        assert_eq!(Cell::from("USD 1,234.5"), Cell::Money(Decimal::new(123450, 2), usd));
+        assert_eq!(Cell::from("1234.5 USD").to_string(), "USD 1234.50");
+        assert_eq!(Cell::from("USD (20)").to_string(), "USD -20.00");
+        assert_eq!(Cell::from("JPY 1234.5").to_string(), "JPY 1235");
+        assert_eq!(Cell::from("BHD 1.2345").to_string(), "BHD 1.235");
+        assert_eq!(Cell::parse("1.234,5 EUR", &ParseOptions::german()).to_string(), "EUR 1234.50");
+        assert!(Cell::from("ABC 12").is_text());
+        assert!(Cell::from("USD abc").is_text());
+        assert!(Cell::from("USD").is_text());
+        let number = ParseOptions { inference: Inference::AlwaysNumber, ..Default::default() };
+        assert!(Cell::try_parse("USD 1", &number).unwrap().is_money());
+        let price = Cell::money(Decimal::new(1005, 3), usd);
+        assert_eq!(price.currency(), Some(usd));
+        assert_eq!(price.to_decimal(), Some(Decimal::new(101, 2)));
+        assert!(!price.is_number());
+        assert_eq!(&price + &Cell::from("USD 1"), Cell::money(Decimal::new(201, 2), usd));
+        assert_eq!(&price - &Cell::from("USD 1"), Cell::money(Decimal::new(1, 2), usd));
+        assert_eq!(&Cell::from("2") * &price, Cell::money(Decimal::new(202, 2), usd));
+        assert_eq!(&price / &Cell::from("3"), Cell::money(Decimal::new(34, 2), usd));
+        assert_eq!(&price / &Cell::from("USD 2.02"), Cell::from("0.5"));
+        assert_eq!(&Cell::money(Decimal::from(100), jpy) * &Cell::from("0.333"), Cell::money(Decimal::from(33), jpy));
+        assert_eq!(&Cell::money(Decimal::ONE, bhd) / &Cell::from("3"), Cell::money(Decimal::new(333, 3), bhd));
+        // mixed currencies are errors
+        assert_eq!(price.checked_add(&Cell::from("EUR 1")), Err(CellError::Value));
+        assert_eq!(&price - &Cell::from("EUR 1"), Cell::Error(CellError::Value));
+        assert_eq!(&price * &price, Cell::Error(CellError::Value));
+        // numbers have no currency, even with a currency symbol
+        assert_eq!(price.checked_add(&Cell::from("1")), Err(CellError::Value));
+        assert_eq!(&Cell::from("1") - &price, Cell::Error(CellError::Value));
+        assert_eq!(&Cell::from("$1.00") + &Cell::from("EUR 1.00"), Cell::Error(CellError::Value));
+        assert_eq!(&price / &Cell::from("JPY 1"), Cell::Error(CellError::Value));
+        assert_eq!(&Cell::from("1") / &price, Cell::Error(CellError::Value));
+        assert_eq!(&price / &Cell::from("0"), Cell::Error(CellError::DivZero));
+        // money does not move dates
+        assert_eq!(&Cell::from("2024-01-31") + &price, Cell::from("2024-01-31"));
+        let mut cell = price.clone();
+        cell.mul_value(Decimal::new(5, 1));
+        assert_eq!(cell.to_string(), "USD 0.51");
+        // values are amounts in the currency of the money
+        assert_eq!(cell.checked_add_value(Decimal::ONE), Ok(()));
+        cell.sub_value(Decimal::new(2, 2));
+        assert_eq!(cell.to_string(), "USD 1.49");
+        // currency symbols that are ISO 4217 codes are money
+        assert_eq!(Cell::from("CHF 2.00"), Cell::money(Decimal::TWO, Currency::from_code("CHF").unwrap()));
+        assert!(Cell::from("(CHF 2.00)").is_money());
+        assert!(Cell::from("2.00 CHF").is_money());
+        assert!(Cell::from("$1.00").format().is_some());
+        assert!(price.is_numeric());
+        assert!(Cell::from("$1.00").is_numeric());
+        assert!(!Cell::from("USD").is_numeric());
+        assert_eq!(price.compare_value("USD 2"), Some(Ordering::Less));
+        assert_eq!(price.compare_value("EUR 2"), None);
+        assert_eq!(price.compare_value("2"), None);
+        assert_eq!(cell_from_value(serde_json::json!({"t":"m","v":"USD 1.01"}), &ParseOptions::default(), 0), Ok(price));
+        assert!(cell_from_value(serde_json::json!({"t":"m","v":"1.01"}), &ParseOptions::default(), 0).is_err());
+    }
+
+    #[test]
+    fn test_cell_date_arithmetic() {
+        let date = Cell::from("2024-01-31");
+        assert_eq!((&date + &Cell::from("1")).to_string(), "2024-02-01");
+        assert_eq!((&date - &Cell::from("31")).to_string(), "2023-12-31");
+        assert_eq!((&date + &Cell::from("0.5")).to_string(), "2024-01-31T12:00:00");
+        assert_eq!((&date - &Cell::from("2023-12-31")).to_decimal(), Some(Decimal::from(31)));
+        assert_eq!((&Cell::from("2024-01-31T18:00:00") - &date).to_decimal(), Some(Decimal::new(75, 2)));
+        assert_eq!(&date + &date, date);
+        assert_eq!(&date * &Cell::from("2"), date);
+        assert_eq!(&Cell::from("1") + &date, Cell::from("1"));
+        let mut cell = date.clone();
+        cell.add_value(Decimal::from(29));
+        assert_eq!(cell.to_string(), "2024-02-29");
+        cell.sub_value(Decimal::new(25, 2));
+        assert_eq!(cell.to_string(), "2024-02-28T18:00:00");
+        // out of range dates are unchanged
+        assert_eq!(&date + &Cell::from("1e20"), date);
+        assert_eq!(&Cell::Date(NaiveDate::MAX) + &Cell::from("1"), Cell::Date(NaiveDate::MAX));
+    }
+
+    #[test]
+    fn test_cell_bool_empty_arithmetic() {
+        let number = Cell::from("2");
+        assert_eq!(&number + &Cell::Empty, number);
+        assert_eq!(&number * &Cell::Bool(true), number);
+        assert_eq!(&Cell::Empty + &number, Cell::Empty);
+        assert_eq!(&Cell::Bool(true) - &number, Cell::Bool(true));
+        let mut cell = Cell::Empty;
+        cell.add_value(Decimal::from(1));
+        assert_eq!(cell, Cell::Empty);
+    }
+
+    #[test]
+    fn test_compare_value_typed() {
+        assert_eq!(Cell::Bool(false).compare_value("true"), Some(Ordering::Less));
+        assert_eq!(Cell::Bool(true).compare_value(&Cell::Bool(true)), Some(Ordering::Equal));
+        assert_eq!(Cell::Bool(true).compare_value("1"), None);
+        assert_eq!(Cell::from("2024-01-31").compare_value("2024-01-31T00:00:00"), Some(Ordering::Equal));
+        assert_eq!(Cell::from("2024-01-31").compare_value("2024-01-30"), Some(Ordering::Greater));
+        assert_eq!(Cell::from("2024-01-31").compare_value("20240131"), None);
+        assert_eq!(Cell::Empty.compare_value(""), Some(Ordering::Equal));
+        assert_eq!(Cell::Empty.compare_value("0"), None);
+        assert!(!Cell::from("0").equal_value(""));
     }
 
     #[test]
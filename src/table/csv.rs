@@ -0,0 +1,336 @@
+use std::io::{Error, ErrorKind, Write};
+use super::cell::ParseOptions;
+
+/// How fields are enclosed by the quote character when writing csv.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CsvQuoting {
+    /// Every field is enclosed by the quote character.
+    Always,
+    /// Only fields containing the delimiter, the quote character or a line break are enclosed by
+    /// the quote character.
+    #[default]
+    Minimal,
+    /// Fields are never enclosed by the quote character.  When reading, the quote character is
+    /// treated as ordinary text.
+    ///
+    /// Without an escape character, writing a field containing the delimiter or a line break is
+    /// an error.
+    Never,
+}
+
+/// How a quote character inside a field is escaped.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CsvEscape {
+    /// The quote character is doubled, per RFC 4180 (`""`).
+    #[default]
+    Double,
+    /// The quote character is preceded by an escape character, for example `\"`.
+    ///
+    /// When fields are not quoted, the delimiter and line breaks are also preceded by the escape
+    /// character.  The escape character itself is always escaped.
+    Char(char),
+}
+
+/// The line terminator written after each row.
+///
+/// When reading, `\n`, `\r\n` and `\r` are always accepted.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CsvTerminator {
+    /// Unix line ending (`\n`).
+    #[default]
+    Lf,
+    /// Windows line ending (`\r\n`), as specified by RFC 4180.
+    CrLf,
+}
+
+impl CsvTerminator {
+
+    /// Returns the line terminator as a string.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CsvTerminator::Lf => "\n",
+            CsvTerminator::CrLf => "\r\n",
+        }
+    }
+
+}
+
+/// Describes the csv dialect used to read and write a `Table`.
+///
+/// The default dialect matches `Table::write_csv`: comma delimited, minimal quoting with `"`,
+/// doubled quotes, `\n` line endings and a trailing line break.
+///
+/// # Examples
+///
+/// ```
+/// use tablefi::{CsvOptions, CsvQuoting, CsvTerminator, ParseOptions, Table};
+///
+/// let options = CsvOptions {
+///     delimiter: ';',
+///     quoting: CsvQuoting::Always,
+///     terminator: CsvTerminator::CrLf,
+///     ..Default::default()
+/// };
+/// let table = Table::from_csv_with("a;b\r\n1;2\r\n", &options).unwrap();
+/// assert_eq!(table.to_csv_with(&options).unwrap(), "\"a\";\"b\"\r\n\"1\";\"2\"\r\n");
+///
+/// // tab separated values
+/// let table = Table::from_csv_with("a\tb\n1\t2\n", &CsvOptions::tsv()).unwrap();
+/// assert_eq!(table.to_string(), r#"[["a","b"],["1","2"]]"#);
+///
+/// // european numbers
+/// let options = CsvOptions { delimiter: ';', parse: ParseOptions::german(), ..Default::default() };
+/// let table = Table::from_csv_with("a;b\n1.234,5;2\n", &options).unwrap();
+/// assert_eq!(table.to_string(), r#"[["a","b"],["1234.5","2"]]"#);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CsvOptions {
+    /// The character separating fields.
+    pub delimiter: char,
+    /// The character used to enclose fields.
+    pub quote: char,
+    /// How a quote character inside a field is escaped.
+    pub escape: CsvEscape,
+    /// When fields are enclosed by the quote character.
+    pub quoting: CsvQuoting,
+    /// The line terminator written after each row.
+    pub terminator: CsvTerminator,
+    /// Whether the last row is followed by a line terminator.
+    pub trailing_newline: bool,
+    /// Whether the first row is a header of column names.  When reading, the header is kept as
+    /// text and the table has a header, as with `Table::with_header`.
+    pub header: bool,
+    /// How fields are parsed into cells when reading.
+    pub parse: ParseOptions,
+}
+
+impl Default for CsvOptions {
+
+    fn default() -> Self {
+        CsvOptions {
+            delimiter: ',',
+            quote: '"',
+            escape: CsvEscape::Double,
+            quoting: CsvQuoting::Minimal,
+            terminator: CsvTerminator::Lf,
+            trailing_newline: true,
+            header: false,
+            parse: ParseOptions::default(),
+        }
+    }
+
+}
+
+impl CsvOptions {
+
+    /// Options for tab separated values, such as those pasted from a spreadsheet.
+    pub fn tsv() -> Self {
+        CsvOptions {
+            delimiter: '\t',
+            ..Default::default()
+        }
+    }
+
+    /// Options for RFC 4180 csv with `\r\n` line endings.
+    pub fn rfc4180() -> Self {
+        CsvOptions {
+            terminator: CsvTerminator::CrLf,
+            ..Default::default()
+        }
+    }
+
+    /// The escape character, if quotes are not escaped by doubling.
+    fn escape_char(&self) -> Option<char> {
+        match self.escape {
+            CsvEscape::Double => None,
+            CsvEscape::Char(c) => Some(c),
+        }
+    }
+
+    /// Checks that the delimiter, the quote character and the escape character are distinct from
+    /// each other and from line breaks.
+    pub fn validate(&self) -> std::io::Result<()> {
+        let mut chars = vec![("delimiter", self.delimiter), ("quote", self.quote)];
+        if let Some(e) = self.escape_char() {
+            chars.push(("escape", e));
+        }
+        for (i, (name, c)) in chars.iter().enumerate() {
+            if *c == '\n' || *c == '\r' {
+                return Err(Error::new(ErrorKind::InvalidInput, format!("Csv {} cannot be a line break", name)));
+            }
+            if let Some((other, _)) = chars[..i].iter().find(|(_, other)| other == c) {
+                return Err(Error::new(ErrorKind::InvalidInput, format!("Csv {} and {} must be different, both are {:?}", other, name, c)));
+            }
+        }
+        Ok(())
+    }
+
+    /// Writes a single field, quoting and escaping as required.
+    ///
+    /// Fails with `ErrorKind::InvalidInput` when a field that is never quoted contains the
+    /// delimiter or a line break that cannot be escaped.
+    pub(crate) fn write_field<W: Write>(&self, writer: &mut W, s: &str) -> std::io::Result<()> {
+        let escape = self.escape_char();
+        let quoting = match self.quoting {
+            CsvQuoting::Always => true,
+            CsvQuoting::Minimal => s.chars().any(|c| c == self.delimiter
+                || c == self.quote
+                || c == '\n'
+                || c == '\r'),
+            CsvQuoting::Never => false,
+        };
+        if !quoting && escape.is_none() && s.chars().any(|c| c == self.delimiter || c == '\n' || c == '\r') {
+            return Err(Error::new(ErrorKind::InvalidInput, format!("Csv field {:?} contains the delimiter or a line break and is not quoted or escaped", s)));
+        }
+        let mut field = String::with_capacity(s.len() + 2);
+        if quoting { field.push(self.quote); }
+        for c in s.chars() {
+            match escape {
+                // per RFC 4180, double quotes require two consecutive double quotes ("")
+                None if c == self.quote && quoting => field.push(self.quote),
+                Some(e) if c == e || c == self.quote => field.push(e),
+                Some(e) if !quoting && (c == self.delimiter || c == '\n' || c == '\r') => field.push(e),
+                _ => {}
+            }
+            field.push(c);
+        }
+        if quoting { field.push(self.quote); }
+        writer.write_all(field.as_bytes())
+    }
+
+    /// Splits csv text into records of fields.
+    pub(crate) fn parse(&self, value: &str) -> std::io::Result<Vec<Vec<String>>> {
+        self.validate()?;
+        let escape = self.escape_char();
+        let mut records: Vec<Vec<String>> = Vec::new();
+        let mut record: Vec<String> = Vec::new();
+        let mut field = String::new();
+        // whether the current field started with a quote
+        let mut quoted = false;
+        // whether the closing quote of a quoted field has been seen
+        let mut closed = false;
+        let mut chars = value.chars().peekable();
+        while let Some(c) = chars.next() {
+            if Some(c) == escape && !closed {
+                match chars.next() {
+                    Some(next) => field.push(next),
+                    None => field.push(c),
+                }
+                continue;
+            }
+            if quoted && !closed {
+                if c == self.quote {
+                    // per RFC 4180, two consecutive double quotes ("") represent one double quote
+                    if escape.is_none() && chars.peek() == Some(&self.quote) {
+                        chars.next();
+                        field.push(self.quote);
+                    } else {
+                        closed = true;
+                    }
+                } else {
+                    field.push(c);
+                }
+                continue;
+            }
+            match c {
+                _ if c == self.delimiter => {
+                    record.push(std::mem::take(&mut field));
+                    quoted = false;
+                    closed = false;
+                }
+                '\r' | '\n' => {
+                    if c == '\r' && chars.peek() == Some(&'\n') {
+                        chars.next();
+                    }
+                    record.push(std::mem::take(&mut field));
+                    records.push(std::mem::take(&mut record));
+                    quoted = false;
+                    closed = false;
+                }
+                _ if c == self.quote && field.is_empty() && !quoted && self.quoting != CsvQuoting::Never => quoted = true,
+                _ if closed => {
+                    return Err(Error::new(ErrorKind::InvalidData, format!("Unexpected character {:?} after closing quote in csv row {}", c, records.len() + 1)));
+                }
+                _ => field.push(c),
+            }
+        }
+        if quoted && !closed {
+            return Err(Error::new(ErrorKind::InvalidData, format!("Unterminated quoted field in csv row {}", records.len() + 1)));
+        }
+        // a line break after the last record does not start a new record
+        if quoted || !field.is_empty() || !record.is_empty() {
+            record.push(field);
+            records.push(record);
+        }
+        Ok(records)
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write(options: &CsvOptions, s: &str) -> String {
+        let mut writer: Vec<u8> = Vec::new();
+        options.write_field(&mut writer, s).unwrap();
+        String::from_utf8(writer).unwrap()
+    }
+
+    #[test]
+    fn test_write_field() {
+        let options = CsvOptions::default();
+        assert_eq!(write(&options, "abc"), "abc");
+        assert_eq!(write(&options, "a,c"), "\"a,c\"");
+        assert_eq!(write(&options, "a\"c"), "\"a\"\"c\"");
+        let options = CsvOptions { delimiter: ';', ..Default::default() };
+        assert_eq!(write(&options, "a,c"), "a,c");
+        assert_eq!(write(&options, "a;c"), "\"a;c\"");
+        let options = CsvOptions { quoting: CsvQuoting::Always, ..Default::default() };
+        assert_eq!(write(&options, "abc"), "\"abc\"");
+        let options = CsvOptions { quote: '\'', ..Default::default() };
+        assert_eq!(write(&options, "a'c"), "'a''c'");
+        assert_eq!(write(&options, "a\"c"), "a\"c");
+    }
+
+    #[test]
+    fn test_write_field_escape() {
+        let options = CsvOptions { escape: CsvEscape::Char('\\'), ..Default::default() };
+        assert_eq!(write(&options, "a\"c"), "\"a\\\"c\"");
+        assert_eq!(write(&options, "a\\c"), "a\\\\c");
+        let options = CsvOptions { escape: CsvEscape::Char('\\'), quoting: CsvQuoting::Never, ..Default::default() };
+        assert_eq!(write(&options, "a,b\nc"), "a\\,b\\\nc");
+        let options = CsvOptions { quoting: CsvQuoting::Never, ..Default::default() };
+        assert_eq!(write(&options, "a\"c"), "a\"c");
+        for s in ["a,c", "a\nc", "a\rc"] {
+            let mut writer: Vec<u8> = Vec::new();
+            assert_eq!(options.write_field(&mut writer, s).unwrap_err().kind(), ErrorKind::InvalidInput);
+        }
+    }
+
+    #[test]
+    fn test_validate() {
+        assert!(CsvOptions::default().validate().is_ok());
+        assert!(CsvOptions { escape: CsvEscape::Char('\\'), ..CsvOptions::tsv() }.validate().is_ok());
+        assert!(CsvOptions { delimiter: '"', ..Default::default() }.validate().is_err());
+        assert!(CsvOptions { delimiter: '\n', ..Default::default() }.validate().is_err());
+        assert!(CsvOptions { quote: '\r', ..Default::default() }.validate().is_err());
+        assert!(CsvOptions { escape: CsvEscape::Char('"'), ..Default::default() }.validate().is_err());
+        assert!(CsvOptions { escape: CsvEscape::Char(','), ..Default::default() }.validate().is_err());
+        let err = CsvOptions { quote: ',', ..Default::default() }.validate().unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    fn test_parse() {
+        let options = CsvOptions::tsv();
+        assert_eq!(options.parse("a\tb,c\n\"1\t2\"\t3").unwrap(), vec![vec!["a", "b,c"], vec!["1\t2", "3"]]);
+        let options = CsvOptions { escape: CsvEscape::Char('\\'), ..Default::default() };
+        assert_eq!(options.parse("\"a\\\"b\",c\\,d\n").unwrap(), vec![vec!["a\"b", "c,d"]]);
+        let options = CsvOptions { quoting: CsvQuoting::Never, ..Default::default() };
+        assert_eq!(options.parse("\"a\",b\"\n").unwrap(), vec![vec!["\"a\"", "b\""]]);
+        let options = CsvOptions { quote: '\'', ..Default::default() };
+        assert_eq!(options.parse("'a,''b',\"c\"\n").unwrap(), vec![vec!["a,'b", "\"c\""]]);
+    }
+
+}
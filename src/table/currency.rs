@@ -0,0 +1,119 @@
+use rust_decimal::{Decimal, RoundingStrategy};
+use std::fmt;
+
+/// ISO 4217 currency codes and their minor units, sorted by code.
+const CURRENCIES: &[(&str, u32)] = &[
+    ("AED", 2), ("AFN", 2), ("ALL", 2), ("AMD", 2), ("ANG", 2), ("AOA", 2), ("ARS", 2), ("AUD", 2),
+    ("AWG", 2), ("AZN", 2), ("BAM", 2), ("BBD", 2), ("BDT", 2), ("BGN", 2), ("BHD", 3), ("BIF", 0),
+    ("BMD", 2), ("BND", 2), ("BOB", 2), ("BRL", 2), ("BSD", 2), ("BTN", 2), ("BWP", 2), ("BYN", 2),
+    ("BZD", 2), ("CAD", 2), ("CDF", 2), ("CHF", 2), ("CLF", 4), ("CLP", 0), ("CNY", 2), ("COP", 2),
+    ("CRC", 2), ("CUP", 2), ("CVE", 2), ("CZK", 2), ("DJF", 0), ("DKK", 2), ("DOP", 2), ("DZD", 2),
+    ("EGP", 2), ("ERN", 2), ("ETB", 2), ("EUR", 2), ("FJD", 2), ("FKP", 2), ("GBP", 2), ("GEL", 2),
+    ("GHS", 2), ("GIP", 2), ("GMD", 2), ("GNF", 0), ("GTQ", 2), ("GYD", 2), ("HKD", 2), ("HNL", 2),
+    ("HTG", 2), ("HUF", 2), ("IDR", 2), ("ILS", 2), ("INR", 2), ("IQD", 3), ("IRR", 2), ("ISK", 0),
+    ("JMD", 2), ("JOD", 3), ("JPY", 0), ("KES", 2), ("KGS", 2), ("KHR", 2), ("KMF", 0), ("KPW", 2),
+    ("KRW", 0), ("KWD", 3), ("KYD", 2), ("KZT", 2), ("LAK", 2), ("LBP", 2), ("LKR", 2), ("LRD", 2),
+    ("LSL", 2), ("LYD", 3), ("MAD", 2), ("MDL", 2), ("MGA", 2), ("MKD", 2), ("MMK", 2), ("MNT", 2),
+    ("MOP", 2), ("MRU", 2), ("MUR", 2), ("MVR", 2), ("MWK", 2), ("MXN", 2), ("MYR", 2), ("MZN", 2),
+    ("NAD", 2), ("NGN", 2), ("NIO", 2), ("NOK", 2), ("NPR", 2), ("NZD", 2), ("OMR", 3), ("PAB", 2),
+    ("PEN", 2), ("PGK", 2), ("PHP", 2), ("PKR", 2), ("PLN", 2), ("PYG", 0), ("QAR", 2), ("RON", 2),
+    ("RSD", 2), ("RUB", 2), ("RWF", 0), ("SAR", 2), ("SBD", 2), ("SCR", 2), ("SDG", 2), ("SEK", 2),
+    ("SGD", 2), ("SHP", 2), ("SLE", 2), ("SOS", 2), ("SRD", 2), ("SSP", 2), ("STN", 2), ("SVC", 2),
+    ("SYP", 2), ("SZL", 2), ("THB", 2), ("TJS", 2), ("TMT", 2), ("TND", 3), ("TOP", 2), ("TRY", 2),
+    ("TTD", 2), ("TWD", 2), ("TZS", 2), ("UAH", 2), ("UGX", 0), ("USD", 2), ("UYI", 0), ("UYU", 2),
+    ("UYW", 4), ("UZS", 2), ("VES", 2), ("VND", 0), ("VUV", 0), ("WST", 2), ("XAF", 0), ("XCD", 2),
+    ("XCG", 2), ("XOF", 0), ("XPF", 0), ("YER", 2), ("ZAR", 2), ("ZMW", 2), ("ZWG", 2),
+];
+
+/// An ISO 4217 currency, like `USD`, along with the number of minor units of the currency.
+///
+/// # Examples
+///
+/// ```
+/// use rust_decimal::Decimal;
+/// use tablefi::Currency;
+///
+/// let jpy = Currency::from_code("JPY").unwrap();
+/// assert_eq!(jpy.minor_units(), 0);
+/// assert_eq!(jpy.round(Decimal::new(1235, 1)).to_string(), "124");
+///
+/// let bhd = Currency::from_code("BHD").unwrap();
+/// assert_eq!(bhd.round(Decimal::new(15, 1)).to_string(), "1.500");
+/// assert_eq!(Currency::from_code("ABC"), None);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Currency {
+    code: &'static str,
+    minor_units: u32,
+}
+
+impl Currency {
+
+    /// Returns the currency with an ISO 4217 code, like `USD`, or `None` if the code is unknown.
+    /// Codes are upper case.
+    pub fn from_code(code: &str) -> Option<Currency> {
+        CURRENCIES.binary_search_by(|(c, _)| (*c).cmp(code))
+            .ok()
+            .map(|i| Currency { code: CURRENCIES[i].0, minor_units: CURRENCIES[i].1 })
+    }
+
+    /// The ISO 4217 code of the currency, like `USD`.
+    pub fn code(&self) -> &'static str {
+        self.code
+    }
+
+    /// The number of decimal places of the currency, like `2` for `USD` or `0` for `JPY`.
+    pub fn minor_units(&self) -> u32 {
+        self.minor_units
+    }
+
+    /// Rounds an amount to the minor units of the currency, with halves rounded away from zero,
+    /// and pads it with zeros to the minor units.
+    pub fn round(&self, value: Decimal) -> Decimal {
+        let mut rounded = value.round_dp_with_strategy(self.minor_units, RoundingStrategy::MidpointAwayFromZero);
+        rounded.rescale(self.minor_units);
+        rounded
+    }
+
+}
+
+impl fmt::Display for Currency {
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.code)
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_currencies_sorted() {
+        assert!(CURRENCIES.windows(2).all(|w| w[0].0 < w[1].0));
+    }
+
+    #[test]
+    fn test_from_code() {
+        assert_eq!(Currency::from_code("USD").map(|c| c.minor_units()), Some(2));
+        assert_eq!(Currency::from_code("KWD").map(|c| c.minor_units()), Some(3));
+        assert_eq!(Currency::from_code("AED").map(|c| c.code()), Some("AED"));
+        assert_eq!(Currency::from_code("ZWG").map(|c| c.to_string()), Some("ZWG".to_string()));
+        assert_eq!(Currency::from_code("usd"), None);
+        assert_eq!(Currency::from_code(""), None);
+    }
+
+    #[test]
+    fn test_round() {
+        let usd = Currency::from_code("USD").unwrap();
+        assert_eq!(usd.round(Decimal::new(1005, 3)).to_string(), "1.01");
+        assert_eq!(usd.round(Decimal::new(-1005, 3)).to_string(), "-1.01");
+        assert_eq!(usd.round(Decimal::from(3)).to_string(), "3.00");
+        let jpy = Currency::from_code("JPY").unwrap();
+        assert_eq!(jpy.round(Decimal::new(5, 1)).to_string(), "1");
+        let bhd = Currency::from_code("BHD").unwrap();
+        assert_eq!(bhd.round(Decimal::new(12345, 4)).to_string(), "1.235");
+    }
+
+}
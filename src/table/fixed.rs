@@ -0,0 +1,214 @@
+use std::io::{Error, ErrorKind, Read, Write};
+use super::cell::{cell_from_field, Cell, ParseOptions};
+use super::table::Table;
+
+/// Describes a column of fixed width text.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FixedWidthColumn {
+    /// The name of the column.
+    pub name: String,
+    /// The zero based character offset of the column in each line.
+    pub start: usize,
+    /// The number of characters in the column.
+    pub width: usize,
+}
+
+impl FixedWidthColumn {
+
+    /// Creates a column with a name, start offset and width.
+    pub fn new(name: &str, start: usize, width: usize) -> Self {
+        FixedWidthColumn {
+            name: name.to_string(),
+            start,
+            width,
+        }
+    }
+
+}
+
+/// Describes the columns used to read and write a `Table` as fixed width text.
+///
+/// # Examples
+///
+/// ```
+/// use tablefi::{FixedWidthColumn, FixedWidthOptions, Table};
+///
+/// let options = FixedWidthOptions {
+///     columns: vec![FixedWidthColumn::new("date", 0, 8), FixedWidthColumn::new("amount", 8, 10)],
+///     ..Default::default()
+/// };
+/// let table = Table::from_fixed_width("20240131   1234.50\n20240201     -3.00\n", &options).unwrap();
+/// assert_eq!(table.to_string(), r#"[["date","amount"],["20240131","1234.50"],["20240201","-3.00"]]"#);
+/// assert_eq!(table.to_fixed_width(&options).unwrap(), "20240131   1234.50\n20240201     -3.00\n");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FixedWidthOptions {
+    /// The columns of each line, in the order of the columns of the table.
+    pub columns: Vec<FixedWidthColumn>,
+    /// Whether the names of the columns are added as the header when reading.  When writing, the
+    /// header of a table with `Table::has_header` is not written, whatever this option is.
+    pub header: bool,
+    /// How fields are parsed into cells when reading.
+    pub parse: ParseOptions,
+}
+
+impl Default for FixedWidthOptions {
+
+    fn default() -> Self {
+        FixedWidthOptions {
+            columns: Vec::new(),
+            header: true,
+            parse: ParseOptions::default(),
+        }
+    }
+
+}
+
+impl Table {
+
+    /// Reads a table from fixed width text.
+    pub fn read_fixed_width<R: Read>(reader: &mut R, options: &FixedWidthOptions) -> std::io::Result<Table> {
+        let mut s = String::new();
+        reader.read_to_string(&mut s)?;
+        Table::from_fixed_width(&s, options)
+    }
+
+    /// Parses a table from fixed width text.
+    ///
+    /// Each field is trimmed of padding and parsed into a cell.  Blank lines are skipped and
+    /// fields past the end of a short line are empty.  Offsets and widths are counted in
+    /// characters.
+    pub fn from_fixed_width(value: &str, options: &FixedWidthOptions) -> Result<Table, std::io::Error> {
+        let mut table = match options.header {
+            true => Table::with_header(options.columns.iter().map(|column| &column.name)),
+            false => Table::new(),
+        };
+        for line in value.lines().filter(|line| !line.trim().is_empty()) {
+            let chars: Vec<char> = line.chars().collect();
+            let row = options.columns.iter()
+                .enumerate()
+                .map(|(c, column)| {
+                    let field: String = chars.iter().skip(column.start).take(column.width).collect();
+                    cell_from_field(field.trim(), &options.parse, c)
+                })
+                .collect::<Result<Vec<Cell>, String>>()
+                .map_err(|e| Error::new(ErrorKind::InvalidData, e))?;
+            table.push_row(row);
+        }
+        Ok(table)
+    }
+
+    /// Writes the table as fixed width text.
+    ///
+    /// Text is left justified and numbers and money are right justified, padded with spaces to the
+    /// width of their column.  Text longer than its column is truncated, while a number or amount
+    /// of money that does not fit is an error rather than silently changing its value.  Columns of
+    /// the table without a column in the options are not written.  The header is not written,
+    /// since fixed width text has no names of columns.
+    pub fn write_fixed_width<W: Write>(&self, writer: &mut W, options: &FixedWidthOptions) -> std::io::Result<()> {
+        let length = options.columns.iter().map(|column| column.start + column.width).max().unwrap_or(0);
+        for r in self.body_start()..self.rows() {
+            let mut line: Vec<char> = vec![' '; length];
+            let row = self.row(r).unwrap_or_default();
+            for (cell, column) in row.iter().zip(&options.columns) {
+                let s = cell.to_string();
+                let count = s.chars().count();
+                let field: String = match cell.is_numeric() {
+                    true if count > column.width => {
+                        return Err(Error::new(ErrorKind::InvalidData, format!("Number {} is wider than column {} in row {}", s, column.name, r + 1)));
+                    }
+                    true => format!("{:>width$}", s, width = column.width),
+                    false => format!("{:<width$}", s.chars().take(column.width).collect::<String>(), width = column.width),
+                };
+                for (i, c) in field.chars().map(|c| if c == '\n' || c == '\r' { ' ' } else { c }).enumerate() {
+                    line[column.start + i] = c;
+                }
+            }
+            writer.write_all(line.into_iter().collect::<String>().as_bytes())?;
+            writer.write_all(b"\n")?;
+        }
+        Ok(())
+    }
+
+    /// Formats the table as fixed width text.
+    pub fn to_fixed_width(&self, options: &FixedWidthOptions) -> Result<String, std::io::Error> {
+        let mut writer: Vec<u8> = Vec::new();
+        self.write_fixed_width(&mut writer, options)?;
+        Ok(String::from_utf8(writer).unwrap())
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal::Decimal;
+    use super::*;
+    use super::super::cell::Inference;
+
+    fn options() -> FixedWidthOptions {
+        FixedWidthOptions {
+            columns: vec![
+                FixedWidthColumn::new("account", 0, 6),
+                FixedWidthColumn::new("name", 6, 8),
+                FixedWidthColumn::new("balance", 14, 10),
+            ],
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_from_fixed_width() {
+        let text = "001234Smith     1,234.50\r\n\n001235Jones  (2.50)\n001236\n";
+        let table = Table::from_fixed_width(text, &options()).unwrap();
+        assert_eq!(table.rows(), 4);
+        assert_eq!(table.row(0).unwrap().to_string(), r#"["account","name","balance"]"#);
+        assert_eq!(table.cell(1, 2).unwrap().to_decimal(), Some(Decimal::new(123450, 2)));
+        assert_eq!(table.row(2).unwrap().to_string(), r#"["1235","Jones  (","2.50)"]"#);
+        assert_eq!(table.row(3).unwrap().to_string(), r#"["1236","",""]"#);
+        let mut options = FixedWidthOptions { header: false, ..options() };
+        options.parse.column_inference.insert(0, Inference::AlwaysText);
+        let table = Table::read_fixed_width(&mut "001234Smith     1,234.50\n".as_bytes(), &options).unwrap();
+        assert_eq!(table.to_string(), r#"[["001234","Smith","1234.50"]]"#);
+        options.parse.column_inference.insert(1, Inference::AlwaysNumber);
+        options.parse.strict = true;
+        assert!(Table::from_fixed_width("001234Smith     1,234.50\n", &options).is_err());
+    }
+
+    #[test]
+    fn test_to_fixed_width() {
+        let mut table: Table = Table::try_from(r#"[["account","name","balance"],["a1","Smithsonian","(2.50)"],["a2","","$1,234.50"]]"#).unwrap();
+        table.set_header(true);
+        assert_eq!(table.to_fixed_width(&options()).unwrap(), "\
+a1    Smithson    (2.50)
+a2             $1,234.50
+");
+        // a table without a header writes every row, even with the default options
+        let table: Table = Table::try_from(r#"[["a1","Smith","1.50"],["a2","Jones","2"]]"#).unwrap();
+        assert_eq!(table.to_fixed_width(&options()).unwrap(), "a1    Smith         1.50\na2    Jones            2\n");
+        let text = "a1    Smith         1.50\n";
+        let read = Table::from_fixed_width(text, &FixedWidthOptions { header: false, ..options() }).unwrap();
+        assert_eq!(read.to_fixed_width(&options()).unwrap(), text);
+        let options = FixedWidthOptions {
+            columns: vec![FixedWidthColumn::new("balance", 0, 4)],
+            header: false,
+            ..Default::default()
+        };
+        let table: Table = Table::try_from(r#"[["abcdef"],["1234"]]"#).unwrap();
+        assert_eq!(table.to_fixed_width(&options).unwrap(), "abcd\n1234\n");
+        let table: Table = Table::try_from(r#"[["12345"]]"#).unwrap();
+        assert!(table.to_fixed_width(&options).is_err());
+        let mut writer: Vec<u8> = Vec::new();
+        assert!(Table::new().write_fixed_width(&mut writer, &options).is_ok());
+        // money is a number, so it is right justified and never truncated
+        let options = FixedWidthOptions {
+            columns: vec![FixedWidthColumn::new("price", 0, 10)],
+            header: false,
+            ..Default::default()
+        };
+        let table: Table = Table::try_from(r#"[["USD 1.5"]]"#).unwrap();
+        assert_eq!(table.to_fixed_width(&options).unwrap(), "  USD 1.50\n");
+        let table: Table = Table::try_from(r#"[["USD 1234567.89"]]"#).unwrap();
+        assert!(table.to_fixed_width(&options).is_err());
+    }
+
+}
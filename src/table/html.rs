@@ -0,0 +1,178 @@
+use std::io::Write;
+use super::table::Table;
+
+/// Options used to write a `Table` as an html table.
+///
+/// # Examples
+///
+/// ```
+/// use tablefi::{HtmlOptions, Table};
+///
+/// let table: Table = Table::try_from(r#"[["name","amount"],["Smith & Co","$1,234.50"]]"#).unwrap();
+/// let options = HtmlOptions { caption: Some("Q1".to_string()), id: Some("q1".to_string()), ..Default::default() };
+/// assert_eq!(table.to_html_with(&options).unwrap(), "\
+/// <table id=\"q1\">
+///   <caption>Q1</caption>
+///   <thead>
+///     <tr><th>name</th><th>amount</th></tr>
+///   </thead>
+///   <tbody>
+///     <tr><td>Smith &amp; Co</td><td class=\"number\">$1,234.50</td></tr>
+///   </tbody>
+/// </table>
+/// ");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HtmlOptions {
+    /// The row written as the `<thead>`, if any.  All other rows are written in the `<tbody>`.
+    pub header: Option<usize>,
+    /// The text of the `<caption>`, if any.
+    pub caption: Option<String>,
+    /// The `id` attribute of the `<table>`, if any.
+    pub id: Option<String>,
+    /// The `class` attribute of the `<table>`, if any.
+    pub class: Option<String>,
+    /// The `class` attribute of numeric cells, used to align numbers with css.
+    pub number_class: String,
+}
+
+impl Default for HtmlOptions {
+
+    fn default() -> Self {
+        HtmlOptions {
+            header: Some(0),
+            caption: None,
+            id: None,
+            class: None,
+            number_class: "number".to_string(),
+        }
+    }
+
+}
+
+/// Escapes text for html content and attribute values.
+fn escape(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+impl Table {
+
+    /// Writes the table as an html table, with the first row as the header.
+    pub fn write_html<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        self.write_html_with(writer, &HtmlOptions::default())
+    }
+
+    /// Writes the table as an html table using the specified options.
+    ///
+    /// All text is escaped.  Numbers and money have the `number_class` of the options, so they can
+    /// be right aligned with css like `td.number { text-align: right; }`.
+    pub fn write_html_with<W: Write>(&self, writer: &mut W, options: &HtmlOptions) -> std::io::Result<()> {
+        let mut attributes = String::new();
+        if let Some(id) = &options.id {
+            attributes.push_str(&format!(" id=\"{}\"", escape(id)));
+        }
+        if let Some(class) = &options.class {
+            attributes.push_str(&format!(" class=\"{}\"", escape(class)));
+        }
+        writeln!(writer, "<table{}>", attributes)?;
+        if let Some(caption) = &options.caption {
+            writeln!(writer, "  <caption>{}</caption>", escape(caption))?;
+        }
+        let write_row = |writer: &mut W, r: usize, tag: &str| -> std::io::Result<()> {
+            write!(writer, "    <tr>")?;
+            for cell in self.row(r).unwrap_or_default().iter() {
+                match cell.is_numeric() {
+                    true => write!(writer, "<{} class=\"{}\">", tag, escape(&options.number_class))?,
+                    false => write!(writer, "<{}>", tag)?,
+                }
+                write!(writer, "{}</{}>", escape(&cell.to_string()), tag)?;
+            }
+            writeln!(writer, "</tr>")
+        };
+        if let Some(header) = options.header.filter(|header| *header < self.rows()) {
+            writeln!(writer, "  <thead>")?;
+            write_row(writer, header, "th")?;
+            writeln!(writer, "  </thead>")?;
+        }
+        writeln!(writer, "  <tbody>")?;
+        for r in (0..self.rows()).filter(|r| Some(*r) != options.header) {
+            write_row(writer, r, "td")?;
+        }
+        writeln!(writer, "  </tbody>")?;
+        writeln!(writer, "</table>")
+    }
+
+    /// Formats the table as an html table, with the first row as the header.
+    pub fn to_html(&self) -> Result<String, std::io::Error> {
+        self.to_html_with(&HtmlOptions::default())
+    }
+
+    /// Formats the table as an html table using the specified options.
+    pub fn to_html_with(&self, options: &HtmlOptions) -> Result<String, std::io::Error> {
+        let mut writer: Vec<u8> = Vec::new();
+        self.write_html_with(&mut writer, options)?;
+        Ok(String::from_utf8(writer).unwrap())
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escape() {
+        assert_eq!(escape("abc"), "abc");
+        assert_eq!(escape("<a href=\"x\">'b' & c</a>"), "&lt;a href=&quot;x&quot;&gt;&#39;b&#39; &amp; c&lt;/a&gt;");
+    }
+
+    #[test]
+    fn test_to_html() {
+        assert_eq!(Table::new().to_html().unwrap(), "<table>\n  <tbody>\n  </tbody>\n</table>\n");
+        let table: Table = Table::try_from(r#"[["Report","<b>"],["name","total"],["a","(2.50)"]]"#).unwrap();
+        assert_eq!(table.to_html().unwrap(), "\
+<table>
+  <thead>
+    <tr><th>Report</th><th>&lt;b&gt;</th></tr>
+  </thead>
+  <tbody>
+    <tr><td>name</td><td>total</td></tr>
+    <tr><td>a</td><td class=\"number\">(2.50)</td></tr>
+  </tbody>
+</table>
+");
+        let options = HtmlOptions {
+            header: Some(1),
+            class: Some("report \"q1\"".to_string()),
+            number_class: "num".to_string(),
+            ..Default::default()
+        };
+        assert_eq!(table.to_html_with(&options).unwrap(), "\
+<table class=\"report &quot;q1&quot;\">
+  <thead>
+    <tr><th>name</th><th>total</th></tr>
+  </thead>
+  <tbody>
+    <tr><td>Report</td><td>&lt;b&gt;</td></tr>
+    <tr><td>a</td><td class=\"num\">(2.50)</td></tr>
+  </tbody>
+</table>
+");
+        let options = HtmlOptions { header: None, ..Default::default() };
+        assert!(!table.to_html_with(&options).unwrap().contains("<thead>"));
+        let mut writer: Vec<u8> = Vec::new();
+        assert!(table.write_html(&mut writer).is_ok());
+    }
+
+}
@@ -0,0 +1,332 @@
+use serde::de::{MapAccess, Visitor};
+use serde::ser::{Error, SerializeMap};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::value::RawValue;
+use std::collections::HashMap;
+use std::fmt;
+use super::cell::{cell_from_raw, Cell, ParseOptions};
+
+/// How cells are written as json.
+///
+/// # Examples
+///
+/// ```
+/// use tablefi::{JsonStyle, Table};
+///
+/// let table: Table = Table::try_from(r#"[["a","1.20"]]"#).unwrap();
+/// assert_eq!(table.to_json_with(JsonStyle::Text).unwrap(), r#"[["a","1.20"]]"#);
+/// assert_eq!(table.to_json_with(JsonStyle::Typed).unwrap(), r#"[["a",1.20]]"#);
+/// assert_eq!(table.to_json_with(JsonStyle::Tagged).unwrap(), r#"[[{"t":"s","v":"a"},{"t":"n","v":"1.20"}]]"#);
+///
+/// // tagged cells keep their type when read back
+/// let table = Table::try_from(r#"[[{"t":"s","v":"123"},{"t":"n","v":"123"}]]"#).unwrap();
+/// assert!(table.cell(0, 0).unwrap().is_text());
+/// assert!(table.cell(0, 1).unwrap().is_number());
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum JsonStyle {
+    /// Every cell is a json string, like `"1.20"`.  Numbers keep their precision, but cannot be
+    /// told apart from text.
+    #[default]
+    Text,
+    /// Numbers are json numbers with every digit of the decimal, like `1.20`.  Booleans are json
+    /// booleans and empty cells are `null`.  Text, money, dates and errors are json strings, so
+    /// money keeps its currency, like `"USD 1.20"`.
+    Typed,
+    /// Every cell is an object with a type of `"n"` for numbers, `"m"` for money, `"s"` for text,
+    /// `"b"` for booleans, `"d"` for dates, `"e"` for errors or `"z"` for empty cells, and the
+    /// value as a string, like `{"t":"n","v":"1.20"}`.
+    Tagged,
+}
+
+/// Serializes a cell in a json style.
+pub(crate) struct JsonCell<'a>(pub &'a Cell, pub JsonStyle);
+
+impl Serialize for JsonCell<'_> {
+
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let JsonCell(cell, style) = self;
+        match (style, cell) {
+            (JsonStyle::Text, _) => cell.serialize(serializer),
+            (JsonStyle::Typed, Cell::Number(d) | Cell::Formatted(d, _)) => {
+                // raw json keeps every digit, which a float would round
+                RawValue::from_string(d.to_string()).map_err(S::Error::custom)?.serialize(serializer)
+            }
+            (JsonStyle::Typed, Cell::Bool(b)) => b.serialize(serializer),
+            (JsonStyle::Typed, Cell::Empty) => serializer.serialize_unit(),
+            (JsonStyle::Typed, _) => cell.serialize(serializer),
+            (JsonStyle::Tagged, _) => {
+                let tag = match cell {
+                    Cell::Text(_) => "s",
+                    Cell::Number(_) | Cell::Formatted(_, _) => "n",
+                    Cell::Money(_, _) => "m",
+                    Cell::Bool(_) => "b",
+                    Cell::Date(_) | Cell::DateTime(_) => "d",
+                    Cell::Error(_) => "e",
+                    Cell::Empty => "z",
+                };
+                let mut map = serializer.serialize_map(Some(2))?;
+                map.serialize_entry("t", tag)?;
+                map.serialize_entry("v", &cell.to_string())?;
+                map.end()
+            }
+        }
+    }
+
+}
+
+/// Serializes a row as a json record keyed by the header.
+///
+/// When the header has duplicate names, the value of the last column with that name is kept.
+pub(crate) struct JsonRecord<'a> {
+    pub keys: &'a [(String, usize)],
+    pub cells: Vec<&'a Cell>,
+    pub style: JsonStyle,
+}
+
+impl JsonRecord<'_> {
+
+    /// Returns the unique names of the header in order, with the index of the last column for each.
+    pub(crate) fn keys(header: &[String]) -> Vec<(String, usize)> {
+        let mut keys: Vec<(String, usize)> = Vec::with_capacity(header.len());
+        for (c, name) in header.iter().enumerate() {
+            match keys.iter_mut().find(|(key, _)| key == name) {
+                Some(key) => key.1 = c,
+                None => keys.push((name.clone(), c)),
+            }
+        }
+        keys
+    }
+
+}
+
+impl Serialize for JsonRecord<'_> {
+
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut map = serializer.serialize_map(Some(self.keys.len()))?;
+        for (key, c) in self.keys {
+            if let Some(cell) = self.cells.get(*c) {
+                map.serialize_entry(key, &JsonCell(cell, self.style))?;
+            }
+        }
+        map.end()
+    }
+
+}
+
+/// A json object read as its keys and values in the order they appear, without relying on the
+/// `preserve_order` feature of `serde_json`.
+pub(crate) struct JsonObject(pub Vec<(String, Box<RawValue>)>);
+
+impl<'de> Deserialize<'de> for JsonObject {
+
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        struct ObjectVisitor;
+
+        impl<'de> Visitor<'de> for ObjectVisitor {
+            type Value = JsonObject;
+
+            fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str("a json object")
+            }
+
+            fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<JsonObject, A::Error> {
+                let mut entries: Vec<(String, Box<RawValue>)> = Vec::with_capacity(map.size_hint().unwrap_or(0));
+                while let Some(entry) = map.next_entry()? {
+                    entries.push(entry);
+                }
+                Ok(JsonObject(entries))
+            }
+        }
+
+        deserializer.deserialize_map(ObjectVisitor)
+    }
+
+}
+
+/// Options used to read a `Table` from a json array of arrays, like `[["name","2024"],["a","1"]]`.
+///
+/// # Examples
+///
+/// ```
+/// use tablefi::{JsonOptions, Table};
+///
+/// let mut table = Table::with_header(["name", "2024"]);
+/// table.push_row(vec!["a".into(), "1".into()]);
+/// let options = JsonOptions { header: true, ..Default::default() };
+/// let read = Table::from_json_with(&table.to_string(), &options).unwrap();
+/// assert!(read.has_header());
+/// assert!(read.cell(0, 1).unwrap().is_text());
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct JsonOptions {
+    /// Whether the first row is a header of column names.  The header is kept as text and the
+    /// table has a header, as with `Table::with_header`.
+    pub header: bool,
+    /// How values are parsed into cells.
+    pub parse: ParseOptions,
+}
+
+/// Options used to read a `Table` from json records, an array of objects like
+/// `[{"name":"a","amount":"1.00"}]`.
+///
+/// # Examples
+///
+/// ```
+/// use tablefi::{JsonRecordsOptions, JsonStyle, Table};
+///
+/// let json = r#"[{"name":"a","amount":"1.00"},{"amount":"2.00","memo":"x"}]"#;
+/// let table = Table::from_json_records(json, &JsonRecordsOptions::default()).unwrap();
+/// assert_eq!(table.to_string(), r#"[["name","amount","memo"],["a","1.00",""],["","2.00","x"]]"#);
+///
+/// let options = JsonRecordsOptions { add_unknown_keys: false, ..Default::default() };
+/// let table = Table::from_json_records(json, &options).unwrap();
+/// assert_eq!(table.to_string(), r#"[["name","amount"],["a","1.00"],["","2.00"]]"#);
+/// assert_eq!(table.to_json_records().unwrap(), r#"[{"name":"a","amount":"1.00"},{"name":"","amount":"2.00"}]"#);
+/// assert_eq!(table.to_json_records_with(JsonStyle::Typed).unwrap(), r#"[{"name":"a","amount":1.00},{"name":null,"amount":2.00}]"#);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct JsonRecordsOptions {
+    /// Whether keys not found in the first record add new columns.  Otherwise these keys are
+    /// ignored.
+    pub add_unknown_keys: bool,
+    /// How values are parsed into cells.
+    pub parse: ParseOptions,
+}
+
+impl Default for JsonRecordsOptions {
+
+    fn default() -> Self {
+        JsonRecordsOptions {
+            add_unknown_keys: true,
+            parse: ParseOptions::default(),
+        }
+    }
+
+}
+
+impl JsonRecordsOptions {
+
+    /// Converts records into rows of cells, with the header of keys as the first row.
+    ///
+    /// Columns follow the order of keys in the records.  Keys missing from a record are empty
+    /// cells.
+    pub(crate) fn rows(&self, records: Vec<JsonObject>) -> Result<Vec<Vec<Cell>>, String> {
+        let mut header: Vec<String> = Vec::new();
+        let mut index: HashMap<String, usize> = HashMap::new();
+        let mut rows: Vec<Vec<Cell>> = Vec::with_capacity(records.len() + 1);
+        for (r, record) in records.into_iter().enumerate() {
+            let mut row = vec![Cell::default(); header.len()];
+            for (key, val) in record.0 {
+                let col = match index.get(&key) {
+                    Some(col) => *col,
+                    None if r == 0 || self.add_unknown_keys => {
+                        index.insert(key.clone(), header.len());
+                        header.push(key);
+                        row.push(Cell::default());
+                        header.len() - 1
+                    }
+                    None => continue,
+                };
+                row[col] = cell_from_raw(&val, &self.parse, col)?;
+            }
+            rows.push(row);
+        }
+        if header.is_empty() {
+            return Ok(Vec::new());
+        }
+        for row in rows.iter_mut() {
+            row.resize(header.len(), Cell::default());
+        }
+        rows.insert(0, header.into_iter().map(Cell::Text).collect());
+        Ok(rows)
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal::Decimal;
+    use super::*;
+    use super::super::cell::CellError;
+
+    fn json(cell: &Cell, style: JsonStyle) -> String {
+        serde_json::to_string(&JsonCell(cell, style)).unwrap()
+    }
+
+    #[test]
+    fn test_json_cell() {
+        let number = Cell::from(Decimal::new(123456789012345678, 10));
+        assert_eq!(json(&number, JsonStyle::Text), r#""12345678.9012345678""#);
+        assert_eq!(json(&number, JsonStyle::Typed), "12345678.9012345678");
+        assert_eq!(json(&number, JsonStyle::Tagged), r#"{"t":"n","v":"12345678.9012345678"}"#);
+        let text = Cell::from("12\"3");
+        assert_eq!(json(&text, JsonStyle::Typed), r#""12\"3""#);
+        assert_eq!(json(&text, JsonStyle::Tagged), r#"{"t":"s","v":"12\"3"}"#);
+        let formatted = Cell::from("($1.50)");
+        assert_eq!(json(&formatted, JsonStyle::Typed), "-1.50");
+        assert_eq!(json(&formatted, JsonStyle::Tagged), r#"{"t":"n","v":"($1.50)"}"#);
+        let boolean = Cell::Bool(true);
+        assert_eq!(json(&boolean, JsonStyle::Text), r#""true""#);
+        assert_eq!(json(&boolean, JsonStyle::Typed), "true");
+        assert_eq!(json(&boolean, JsonStyle::Tagged), r#"{"t":"b","v":"true"}"#);
+        let date = Cell::from("2024-01-31T09:30:00");
+        assert_eq!(json(&date, JsonStyle::Typed), r#""2024-01-31T09:30:00""#);
+        assert_eq!(json(&date, JsonStyle::Tagged), r#"{"t":"d","v":"2024-01-31T09:30:00"}"#);
+        assert_eq!(json(&Cell::Empty, JsonStyle::Text), "\"\"");
+        assert_eq!(json(&Cell::Empty, JsonStyle::Typed), "null");
+        assert_eq!(json(&Cell::Empty, JsonStyle::Tagged), r#"{"t":"z","v":""}"#);
+        let error = Cell::Error(CellError::DivZero);
+        assert_eq!(json(&error, JsonStyle::Typed), r##""#DIV/0!""##);
+        assert_eq!(json(&error, JsonStyle::Tagged), r##"{"t":"e","v":"#DIV/0!"}"##);
+        let money = Cell::from("USD 1.5");
+        assert_eq!(json(&money, JsonStyle::Typed), r#""USD 1.50""#);
+        assert_eq!(json(&money, JsonStyle::Tagged), r#"{"t":"m","v":"USD 1.50"}"#);
+    }
+
+    #[test]
+    fn test_json_record() {
+        let header: Vec<String> = vec!["a".to_string(), "b".to_string(), "a".to_string()];
+        let keys = JsonRecord::keys(&header);
+        assert_eq!(keys, vec![("a".to_string(), 2), ("b".to_string(), 1)]);
+        let cells = [Cell::from("x"), Cell::from("1"), Cell::from("y")];
+        let record = JsonRecord { keys: &keys, cells: cells.iter().collect(), style: JsonStyle::Typed };
+        assert_eq!(serde_json::to_string(&record).unwrap(), r#"{"a":"y","b":1}"#);
+    }
+
+    fn records(value: &str) -> Vec<JsonObject> {
+        serde_json::from_str(value).unwrap()
+    }
+
+    #[test]
+    fn test_rows() {
+        let options = JsonRecordsOptions::default();
+        let rows = options.rows(records(r#"[{"b":"1","a":"x"},{"a":"y","c":null}]"#)).unwrap();
+        assert_eq!(rows, vec![
+            vec![Cell::from("b"), Cell::from("a"), Cell::from("c")],
+            vec![Cell::from("1"), Cell::from("x"), Cell::default()],
+            vec![Cell::default(), Cell::from("y"), Cell::default()],
+        ]);
+        let options = JsonRecordsOptions { add_unknown_keys: false, ..Default::default() };
+        let rows = options.rows(records(r#"[{"b":"1","a":"x"},{"a":"y","c":"z"}]"#)).unwrap();
+        assert_eq!(rows[0], vec![Cell::from("b"), Cell::from("a")]);
+        assert_eq!(rows[2], vec![Cell::default(), Cell::from("y")]);
+        assert!(options.rows(records(r#"[{}]"#)).unwrap().is_empty());
+        // keys keep their order
+        let rows = options.rows(records(r#"[{"z":"1","y":"2","x":"3"}]"#)).unwrap();
+        assert_eq!(rows[0], vec![Cell::from("z"), Cell::from("y"), Cell::from("x")]);
+        // header names are always text
+        let rows = options.rows(records(r#"[{"2024":"1"}]"#)).unwrap();
+        assert_eq!(rows[0][0], Cell::Text("2024".to_string()));
+    }
+
+}
@@ -0,0 +1,193 @@
+use std::io::Write;
+use super::table::Table;
+
+/// Options used to write a `Table` as a LaTeX `tabular` environment.
+///
+/// # Examples
+///
+/// ```
+/// use tablefi::{LatexOptions, Table};
+///
+/// let table: Table = Table::try_from(r#"[["Item","Amount"],["R&D","$1,200.00"],["Total","$1,200.00"]]"#).unwrap();
+/// let options = LatexOptions { total_rows: vec![2], ..Default::default() };
+/// assert_eq!(table.to_latex_with(&options).unwrap(), r"\begin{tabular}{lr}
+/// \toprule
+/// Item & Amount \\
+/// \midrule
+/// R\&D & \$1,200.00 \\
+/// \midrule
+/// Total & \$1,200.00 \\
+/// \bottomrule
+/// \end{tabular}
+/// ");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LatexOptions {
+    /// Whether the first row is a header, separated from the other rows by a rule.  The header of
+    /// a table with `Table::has_header` is always separated.
+    pub header: bool,
+    /// Whether rules use the `booktabs` package (`\toprule`, `\midrule` and `\bottomrule`).
+    /// Otherwise rules are written as `\hline`.
+    pub booktabs: bool,
+    /// Whether numeric columns use the `S` column type of the `siunitx` package.  Numbers in these
+    /// columns are written as plain decimals without their presentation, so siunitx can align and
+    /// format them, and text is enclosed in braces.  A percentage is written as its value, so
+    /// `12.5%` is written as `0.125`, and money as its amount without the currency.  Leave this off
+    /// to keep the presentation of percentages and money.
+    pub siunitx: bool,
+    /// The rows preceded by a rule, like totals and subtotals.
+    pub total_rows: Vec<usize>,
+}
+
+impl Default for LatexOptions {
+
+    fn default() -> Self {
+        LatexOptions {
+            header: true,
+            booktabs: true,
+            siunitx: false,
+            total_rows: Vec::new(),
+        }
+    }
+
+}
+
+/// Escapes text for LaTeX.
+fn escape(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' | '%' | '$' | '#' | '_' | '{' | '}' => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            '\\' => escaped.push_str(r"\textbackslash{}"),
+            '~' => escaped.push_str(r"\textasciitilde{}"),
+            '^' => escaped.push_str(r"\textasciicircum{}"),
+            '\r' | '\n' => escaped.push(' '),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+impl Table {
+
+    /// Writes the table as a LaTeX `tabular` environment with `booktabs` rules and the first row
+    /// as the header.
+    pub fn write_latex<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        self.write_latex_with(writer, &LatexOptions::default())
+    }
+
+    /// Writes the table as a LaTeX `tabular` environment using the specified options.
+    ///
+    /// Columns where every cell below the header is a number or money are right aligned, or use the
+    /// `S` column type with siunitx.  All other columns are left aligned.  A table without columns
+    /// is written as nothing, since a `tabular` needs at least one column.
+    pub fn write_latex_with<W: Write>(&self, writer: &mut W, options: &LatexOptions) -> std::io::Result<()> {
+        if self.cols() == 0 {
+            return Ok(());
+        }
+        let header = options.header || self.has_header();
+        let skip = match header {
+            true => 1,
+            false => 0,
+        };
+        let numeric: Vec<bool> = (0..self.cols()).map(|c| self.is_numeric_col(c, skip)).collect();
+        let spec: String = numeric.iter()
+            .map(|numeric| match (numeric, options.siunitx) {
+                (true, true) => 'S',
+                (true, false) => 'r',
+                (false, _) => 'l',
+            })
+            .collect();
+        let [top, mid, bottom] = match options.booktabs {
+            true => [r"\toprule", r"\midrule", r"\bottomrule"],
+            false => [r"\hline", r"\hline", r"\hline"],
+        };
+        writeln!(writer, r"\begin{{tabular}}{{{}}}", spec)?;
+        writeln!(writer, "{}", top)?;
+        for r in 0..self.rows() {
+            if (r == 1 && header) || (r > 0 && options.total_rows.contains(&r)) {
+                writeln!(writer, "{}", mid)?;
+            }
+            let fields: Vec<String> = self.row(r).unwrap_or_default().iter()
+                .enumerate()
+                .map(|(c, cell)| match (numeric[c] && options.siunitx, cell.to_decimal()) {
+                    (true, Some(d)) => d.to_string(),
+                    // text in an siunitx column is not parsed as a number when enclosed in braces
+                    (true, None) if !cell.to_string().is_empty() => format!("{{{}}}", escape(&cell.to_string())),
+                    _ => escape(&cell.to_string()),
+                })
+                .collect();
+            writeln!(writer, r"{} \\", fields.join(" & "))?;
+        }
+        writeln!(writer, "{}", bottom)?;
+        writeln!(writer, r"\end{{tabular}}")
+    }
+
+    /// Formats the table as a LaTeX `tabular` environment with `booktabs` rules and the first row
+    /// as the header.
+    pub fn to_latex(&self) -> Result<String, std::io::Error> {
+        self.to_latex_with(&LatexOptions::default())
+    }
+
+    /// Formats the table as a LaTeX `tabular` environment using the specified options.
+    pub fn to_latex_with(&self, options: &LatexOptions) -> Result<String, std::io::Error> {
+        let mut writer: Vec<u8> = Vec::new();
+        self.write_latex_with(&mut writer, options)?;
+        Ok(String::from_utf8(writer).unwrap())
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escape() {
+        assert_eq!(escape("abc"), "abc");
+        assert_eq!(escape("50% of $5 & #1_a {b}"), r"50\% of \$5 \& \#1\_a \{b\}");
+        assert_eq!(escape(r"a\b~c^d"), r"a\textbackslash{}b\textasciitilde{}c\textasciicircum{}d");
+    }
+
+    #[test]
+    fn test_to_latex() {
+        let table: Table = Table::try_from(r#"[["Account","Q1","Q2"],["Sales","(2.50)","12.5%"],["Total","n/a","1.5"]]"#).unwrap();
+        assert_eq!(table.to_latex().unwrap(), r"\begin{tabular}{llr}
+\toprule
+Account & Q1 & Q2 \\
+\midrule
+Sales & (2.50) & 12.5\% \\
+Total & n/a & 1.5 \\
+\bottomrule
+\end{tabular}
+");
+        let table: Table = Table::try_from(r#"[["Account","Q1"],["Sales","(2.50)"],["Costs",""],["Total","-$2.50"]]"#).unwrap();
+        let options = LatexOptions { booktabs: false, siunitx: true, total_rows: vec![3], ..Default::default() };
+        assert_eq!(table.to_latex_with(&options).unwrap(), r"\begin{tabular}{lS}
+\hline
+Account & {Q1} \\
+\hline
+Sales & -2.50 \\
+Costs &  \\
+\hline
+Total & -2.50 \\
+\hline
+\end{tabular}
+");
+        let options = LatexOptions { header: false, ..Default::default() };
+        assert!(!table.to_latex_with(&options).unwrap().contains(r"\midrule"));
+        let mut table = table;
+        table.set_header(true);
+        assert!(table.to_latex_with(&options).unwrap().contains(r"\midrule"));
+        assert_eq!(Table::new().to_latex().unwrap(), "");
+        // siunitx columns write the value of a percentage
+        let table: Table = Table::try_from(r#"[["Rate"],["12.5%"]]"#).unwrap();
+        let options = LatexOptions { siunitx: true, ..Default::default() };
+        assert!(table.to_latex_with(&options).unwrap().contains("0.125 \\\\"));
+        assert!(table.to_latex().unwrap().contains(r"12.5\% \\"));
+    }
+
+}
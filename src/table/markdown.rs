@@ -0,0 +1,233 @@
+use std::io::{Error, ErrorKind, Write};
+use super::cell::{cell_from_field, Cell, ParseOptions};
+use super::table::Table;
+
+/// Escapes text for a markdown table cell.
+///
+/// Pipes and backslashes are escaped and line breaks are written as `<br>`, since a cell cannot
+/// span lines.
+fn escape(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    let mut chars = s.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '|' => escaped.push_str("\\|"),
+            '\\' => escaped.push_str("\\\\"),
+            '\r' if chars.peek() == Some(&'\n') => {}
+            '\r' | '\n' => escaped.push_str("<br>"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Splits a markdown table row into its unescaped cells.
+fn split_row(line: &str) -> Vec<String> {
+    let line = line.trim();
+    // whether the last cell was closed by an unescaped pipe
+    let mut closed = line.starts_with('|');
+    let line = line.strip_prefix('|').unwrap_or(line);
+    let mut cells: Vec<String> = Vec::new();
+    let mut cell = String::new();
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        closed = c == '|';
+        match c {
+            '\\' => match chars.peek() {
+                Some(&next) if next == '|' || next == '\\' => {
+                    cell.push(next);
+                    chars.next();
+                }
+                _ => cell.push(c),
+            },
+            '|' => cells.push(std::mem::take(&mut cell).trim().to_string()),
+            _ => cell.push(c),
+        }
+    }
+    // a trailing pipe closes the last cell, otherwise the rest of the line is the last cell
+    if !closed {
+        cells.push(cell.trim().to_string());
+    }
+    cells
+}
+
+/// Whether the line is the delimiter row separating the header from the body of a table.
+fn is_delimiter_row(line: &str) -> bool {
+    let cells = split_row(line);
+    !cells.is_empty() && cells.iter().all(|cell| {
+        let dashes = cell.strip_prefix(':').unwrap_or(cell);
+        let dashes = dashes.strip_suffix(':').unwrap_or(dashes);
+        !dashes.is_empty() && dashes.chars().all(|c| c == '-')
+    })
+}
+
+impl Table {
+
+    /// Whether every cell in a column after the skipped rows is a number or money, ignoring empty
+    /// cells.
+    pub(crate) fn is_numeric_col(&self, col: usize, skip: usize) -> bool {
+        let body: Vec<Cell> = self.col(col).unwrap_or_default().into_iter().skip(skip).filter(|cell| !cell.to_string().is_empty()).collect();
+        !body.is_empty() && body.iter().all(Cell::is_numeric)
+    }
+
+    /// Writes the table as a GitHub flavored markdown table.
+    ///
+    /// The first row is the header.  Columns where every cell below the header is a number or money
+    /// are right aligned and all other columns are left aligned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tablefi::Table;
+    ///
+    /// let table: Table = Table::try_from(r#"[["name","amount"],["a|b","$1,234.50"],["c","2"]]"#).unwrap();
+    /// assert_eq!(table.to_markdown().unwrap(), "\
+    /// | name |    amount |
+    /// | :--- | --------: |
+    /// | a\\|b | $1,234.50 |
+    /// | c    |         2 |
+    /// ");
+    /// ```
+    pub fn write_markdown<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        let rows: Vec<Vec<String>> = (0..self.rows())
+            .map(|r| self.row(r).unwrap_or_default().iter().map(|cell| escape(&cell.to_string())).collect())
+            .collect();
+        let right: Vec<bool> = (0..self.cols()).map(|c| self.is_numeric_col(c, 1)).collect();
+        let widths: Vec<usize> = (0..self.cols())
+            .map(|c| rows.iter().map(|row| row[c].chars().count()).max().unwrap_or(0).max(3))
+            .collect();
+        let write_row = |writer: &mut W, row: &[String]| -> std::io::Result<()> {
+            writer.write_all(b"|")?;
+            for (c, s) in row.iter().enumerate() {
+                let line = match right[c] {
+                    true => format!(" {:>width$} |", s, width = widths[c]),
+                    false => format!(" {:<width$} |", s, width = widths[c]),
+                };
+                writer.write_all(line.as_bytes())?;
+            }
+            writer.write_all(b"\n")
+        };
+        for (r, row) in rows.iter().enumerate() {
+            write_row(writer, row)?;
+            if r == 0 {
+                let delimiter: Vec<String> = widths.iter()
+                    .zip(&right)
+                    .map(|(width, right)| match right {
+                        true => format!("{}:", "-".repeat(width - 1)),
+                        false => format!(":{}", "-".repeat(width - 1)),
+                    })
+                    .collect();
+                write_row(writer, &delimiter)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Formats the table as a GitHub flavored markdown table.
+    pub fn to_markdown(&self) -> Result<String, std::io::Error> {
+        let mut writer: Vec<u8> = Vec::new();
+        self.write_markdown(&mut writer)?;
+        Ok(String::from_utf8(writer).unwrap())
+    }
+
+    /// Parses a table from a GitHub flavored markdown table.
+    ///
+    /// The header becomes the header of the table, as with `Table::with_header`.  Rows with more
+    /// cells than the header are truncated and rows with fewer cells are padded with empty cells.
+    /// Text before the table is ignored and the table ends at the first line without a cell.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tablefi::Table;
+    ///
+    /// let table = Table::from_markdown("| a | b |\n|---|--:|\n| x\\|y | 1 |\n").unwrap();
+    /// assert_eq!(table.to_string(), r#"[["a","b"],["x|y","1"]]"#);
+    /// ```
+    pub fn from_markdown(value: &str) -> Result<Table, std::io::Error> {
+        Table::from_markdown_with(value, &ParseOptions::default())
+    }
+
+    /// Parses a table from a GitHub flavored markdown table, using the specified options for the
+    /// cells.
+    pub fn from_markdown_with(value: &str, options: &ParseOptions) -> Result<Table, std::io::Error> {
+        let lines: Vec<&str> = value.lines().collect();
+        let start = lines.windows(2)
+            .position(|pair| pair[0].contains('|') && is_delimiter_row(pair[1]) && split_row(pair[0]).len() == split_row(pair[1]).len())
+            .ok_or_else(|| Error::new(ErrorKind::InvalidData, "Markdown table not found"))?;
+        let cols = split_row(lines[start]).len();
+        let mut table = Table::with_header(split_row(lines[start]));
+        let body = lines[start + 2..].iter().take_while(|line| !line.trim().is_empty() && line.contains('|'));
+        for line in body {
+            let mut fields = split_row(line);
+            fields.resize(cols, String::new());
+            let row = fields.iter()
+                .enumerate()
+                .map(|(c, s)| cell_from_field(s, options, c))
+                .collect::<Result<Vec<Cell>, String>>()
+                .map_err(|e| Error::new(ErrorKind::InvalidData, e))?;
+            table.push_row(row);
+        }
+        Ok(table)
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_escape() {
+        assert_eq!(escape("abc"), "abc");
+        assert_eq!(escape("a|b"), "a\\|b");
+        assert_eq!(escape("a\\b"), "a\\\\b");
+        assert_eq!(escape("a\r\nb\nc"), "a<br>b<br>c");
+    }
+
+    #[test]
+    fn test_split_row() {
+        assert_eq!(split_row("| a | b |"), vec!["a", "b"]);
+        assert_eq!(split_row("a | b"), vec!["a", "b"]);
+        assert_eq!(split_row("| a\\|b | c\\\\ |"), vec!["a|b", "c\\"]);
+        assert_eq!(split_row("| a | |"), vec!["a", ""]);
+        assert_eq!(split_row("a | |"), vec!["a", ""]);
+        assert_eq!(split_row("| a | \\"), vec!["a", "\\"]);
+        assert_eq!(split_row("| a | b \\|"), vec!["a", "b |"]);
+        assert!(split_row("|").is_empty());
+        assert!(is_delimiter_row("|:---|---:|:-:|"));
+        assert!(!is_delimiter_row("| a | --- |"));
+    }
+
+    #[test]
+    fn test_to_markdown() {
+        assert_eq!(Table::new().to_markdown().unwrap(), "");
+        let table: Table = Table::try_from(r#"[["id","note","total"],["1","","(2.50)"],["2","a\nb","12.5%"]]"#).unwrap();
+        assert_eq!(table.to_markdown().unwrap(), "\
+|  id | note   |  total |
+| --: | :----- | -----: |
+|   1 |        | (2.50) |
+|   2 | a<br>b |  12.5% |
+");
+        let table: Table = Table::try_from(r#"[["price"],["USD 1.50"],["CHF 20"]]"#).unwrap();
+        assert_eq!(table.to_markdown().unwrap(), "\
+|     price |
+| --------: |
+|  USD 1.50 |
+| CHF 20.00 |
+");
+    }
+
+    #[test]
+    fn test_from_markdown() {
+        let table = Table::from_markdown("Totals\n\n| a | b | c |\n| :- | -: | --- |\n| 1 | x\n| 2 | y | z | w |\n\nafter\n").unwrap();
+        assert_eq!(table.to_string(), r#"[["a","b","c"],["1","x",""],["2","y","z"]]"#);
+        assert_eq!(table.cell(1, 0).unwrap().to_decimal(), Some(rust_decimal::Decimal::from(1)));
+        assert!(Table::from_markdown("| a | b |\n| x | y |\n").is_err());
+        let table = Table::from_markdown_with("| a |\n|---|\n| 1.234,5 |\n", &ParseOptions::german()).unwrap();
+        assert_eq!(table.cell(1, 0).unwrap().to_decimal(), Some(rust_decimal::Decimal::new(12345, 1)));
+        let table: Table = Table::try_from(r#"[["a|b","c\\d"],["$1.00","x"]]"#).unwrap();
+        assert_eq!(Table::from_markdown(&table.to_markdown().unwrap()).unwrap().to_string(), table.to_string());
+    }
+
+}
@@ -1,7 +1,47 @@
+#[cfg(feature = "arrow")]
+pub mod arrow;
 pub mod cell;
+pub mod csv;
+pub mod currency;
+pub mod fixed;
+pub mod html;
+pub mod json;
+pub mod latex;
+pub mod markdown;
+#[cfg(feature = "parquet")]
+pub mod parquet;
+#[cfg(feature = "polars")]
+pub mod polars;
+pub mod render;
 pub mod slice;
+#[cfg(feature = "spreadsheet")]
+pub mod spreadsheet;
+#[cfg(feature = "sqlite")]
+pub mod sqlite;
+pub mod statement;
+#[allow(clippy::module_inception)]
 pub mod table;
+#[cfg(feature = "xlsx")]
+pub mod xlsx;
 
-pub use cell::Cell;
+#[cfg(feature = "arrow")]
+pub use arrow::{ArrowOptions, MixedColumn};
+pub use cell::{Cell, CellError, Inference, NegativeStyle, NumberFormat, ParseOptions};
+pub use csv::{CsvEscape, CsvOptions, CsvQuoting, CsvTerminator};
+pub use currency::Currency;
+pub use fixed::{FixedWidthColumn, FixedWidthOptions};
+pub use html::HtmlOptions;
+pub use json::{JsonOptions, JsonRecordsOptions, JsonStyle};
+pub use latex::LatexOptions;
+#[cfg(feature = "polars")]
+pub use polars::DataFrameError;
+pub use render::{BorderStyle, RenderOptions};
 pub use slice::Slice;
+#[cfg(feature = "spreadsheet")]
+pub use spreadsheet::{Sheet, SpreadsheetOptions};
+#[cfg(feature = "sqlite")]
+pub use sqlite::SqliteMode;
+pub use statement::QifOptions;
 pub use table::Table;
+#[cfg(feature = "xlsx")]
+pub use xlsx::XlsxOptions;
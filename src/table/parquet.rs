@@ -0,0 +1,93 @@
+use bytes::Bytes;
+use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
+use parquet::arrow::ArrowWriter;
+use parquet::basic::Compression;
+use parquet::file::properties::WriterProperties;
+use std::io::{Error, Read, Write};
+use super::arrow::ArrowOptions;
+use super::table::Table;
+
+impl Table {
+
+    /// Reads a table from a parquet file, with a column for each field.
+    ///
+    /// Decimal fields become numbers with the scale of the field, so `1.50` in a `DECIMAL(10, 2)`
+    /// field is read as `1.50`.  String fields become text.  Other fields are converted as
+    /// described by `Table::from_record_batch`.
+    ///
+    /// Nulls become empty cells, the same as `Cell::default()`, so a null and an empty string are
+    /// read the same way.
+    pub fn read_parquet<R: Read>(reader: &mut R, options: &ArrowOptions) -> std::io::Result<Table> {
+        let mut buffer: Vec<u8> = Vec::new();
+        reader.read_to_end(&mut buffer)?;
+        let builder = ParquetRecordBatchReaderBuilder::try_new(Bytes::from(buffer)).map_err(Error::other)?;
+        let mut table = match options.header {
+            true => Table::with_header(builder.schema().fields().iter().map(|field| field.name())),
+            false => Table::new(),
+        };
+        let rows = ArrowOptions { header: false, ..options.clone() };
+        for batch in builder.build().map_err(Error::other)? {
+            let batch = Table::from_record_batch(&batch.map_err(Error::other)?, &rows).map_err(Error::other)?;
+            for r in 0..batch.rows() {
+                table.push_row(batch.row(r).unwrap_or_default());
+            }
+        }
+        Ok(table)
+    }
+
+    /// Writes the table as a parquet file with snappy compression.
+    ///
+    /// Columns are converted as described by `Table::to_record_batch`, so numbers are written as
+    /// decimals with the largest scale of the column.  Empty cells are written as nulls.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tablefi::{ArrowOptions, Table};
+    ///
+    /// let mut table: Table = Table::try_from(r#"[["account","balance"],["cash","1,234.50"],["",""]]"#).unwrap();
+    /// table.set_header(true);
+    /// let mut parquet: Vec<u8> = Vec::new();
+    /// table.write_parquet(&mut parquet, &ArrowOptions::default()).unwrap();
+    /// let table = Table::read_parquet(&mut parquet.as_slice(), &ArrowOptions::default()).unwrap();
+    /// assert_eq!(table.to_string(), r#"[["account","balance"],["cash","1234.50"],["",""]]"#);
+    /// ```
+    pub fn write_parquet<W: Write + Send>(&self, writer: W, options: &ArrowOptions) -> std::io::Result<()> {
+        let batch = self.to_record_batch(options).map_err(Error::other)?;
+        let properties = WriterProperties::builder().set_compression(Compression::SNAPPY).build();
+        let mut writer = ArrowWriter::try_new(writer, batch.schema(), Some(properties)).map_err(Error::other)?;
+        writer.write(&batch).map_err(Error::other)?;
+        writer.close().map_err(Error::other)?;
+        Ok(())
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal::Decimal;
+    use super::*;
+    use super::super::arrow::MixedColumn;
+    use super::super::cell::Cell;
+
+    #[test]
+    fn test_parquet() {
+        let mut table: Table = Table::try_from(r#"[["id","name","amount","rate"],["1","a","(2.50)","12.5%"],["2","","1,000","1"],["3","c","","x"]]"#).unwrap();
+        table.set_header(true);
+        let mut writer: Vec<u8> = Vec::new();
+        table.write_parquet(&mut writer, &ArrowOptions::default()).unwrap();
+        assert!(writer.starts_with(b"PAR1"));
+        let table = Table::read_parquet(&mut writer.as_slice(), &ArrowOptions::default()).unwrap();
+        assert_eq!(table.to_string(), r#"[["id","name","amount","rate"],["1","a","-2.50","12.5%"],["2","","1000.00","1"],["3","c","","x"]]"#);
+        assert_eq!(table.cell(2, 2), Some(Cell::Number(Decimal::new(100000, 2))));
+        assert_eq!(table.cell(1, 3), Some(Cell::Text("12.5%".to_string())));
+        let options = ArrowOptions { header: false, mixed: MixedColumn::Decimal128 };
+        let table = Table::read_parquet(&mut writer.as_slice(), &options).unwrap();
+        assert_eq!(table.rows(), 3);
+        assert!(Table::read_parquet(&mut "a,b\n".as_bytes(), &ArrowOptions::default()).is_err());
+        let options = ArrowOptions { mixed: MixedColumn::Error, ..Default::default() };
+        let table: Table = Table::try_from(r#"[["a"],["1"],["x"]]"#).unwrap();
+        assert!(table.write_parquet(Vec::new(), &options).is_err());
+    }
+
+}
@@ -0,0 +1,223 @@
+use polars::prelude::{AnyValue, Column, DataFrame, Int128Chunked, IntoColumn, NewChunkedArray, PolarsError};
+use rust_decimal::Decimal;
+use std::fmt;
+use std::str::FromStr;
+use super::cell::Cell;
+use super::table::Table;
+
+/// The largest precision of a polars decimal.
+const MAX_PRECISION: usize = 38;
+
+/// An error converting a `Table` to a polars `DataFrame`.
+#[derive(Debug)]
+pub enum DataFrameError {
+    /// The named column has both numbers and text, so it cannot be represented by a single dtype.
+    MixedColumn(String),
+    /// A number in the named column does not fit in a decimal with the largest scale of the
+    /// column.
+    Overflow(String),
+    /// An error from polars, like duplicate column names.
+    Polars(PolarsError),
+}
+
+impl fmt::Display for DataFrameError {
+
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DataFrameError::MixedColumn(name) => write!(f, "Column {} has both numbers and text", name),
+            DataFrameError::Overflow(name) => write!(f, "Column {} has a number that does not fit in a decimal", name),
+            DataFrameError::Polars(e) => write!(f, "{}", e),
+        }
+    }
+
+}
+
+impl std::error::Error for DataFrameError {
+
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            DataFrameError::Polars(e) => Some(e),
+            _ => None,
+        }
+    }
+
+}
+
+impl From<PolarsError> for DataFrameError {
+
+    fn from(e: PolarsError) -> Self {
+        DataFrameError::Polars(e)
+    }
+
+}
+
+/// Converts a polars value to a cell.
+fn cell_from_any_value(value: AnyValue) -> Cell {
+    match value {
+        AnyValue::Null => Cell::default(),
+        AnyValue::Boolean(b) => Cell::Bool(b),
+        AnyValue::Decimal(v, _, scale) => Decimal::try_from_i128_with_scale(v, scale as u32)
+            .map(Cell::Number)
+            .unwrap_or_else(|_| Cell::Text(value.to_string())),
+        AnyValue::String(s) => Cell::Text(s.to_string()),
+        AnyValue::StringOwned(ref s) => Cell::Text(s.to_string()),
+        _ if value.is_signed_integer() => value.extract::<i64>().map(|i| Cell::Number(Decimal::from(i))).unwrap_or_default(),
+        _ if value.is_unsigned_integer() => value.extract::<u64>().map(|i| Cell::Number(Decimal::from(i))).unwrap_or_default(),
+        // the shortest representation of the float avoids binary noise like 0.1 + 0.2
+        _ if value.is_float() => match value.extract::<f64>().map(|f| Decimal::from_str(&f.to_string())) {
+            Some(Ok(d)) => Cell::Number(d),
+            _ => Cell::Text(value.to_string()),
+        },
+        _ => Cell::Text(value.to_string()),
+    }
+}
+
+impl From<&DataFrame> for Table {
+
+    /// Converts a polars `DataFrame` to a table, with the names of the columns as the header.
+    ///
+    /// Decimals become numbers with the scale of their column, and integers and floats become
+    /// numbers.  Strings become text, booleans become `Cell::Bool`, nulls become empty cells and
+    /// other values become their text representation in polars.
+    fn from(df: &DataFrame) -> Self {
+        let mut table = Table::with_header(df.columns().iter().map(|column| column.name().as_str()));
+        for r in 0..df.height() {
+            table.push_row(df.columns().iter()
+                .map(|column| column.get(r).map(cell_from_any_value).unwrap_or_default())
+                .collect::<Vec<Cell>>());
+        }
+        table
+    }
+
+}
+
+impl Table {
+
+    /// Converts the table to a polars `DataFrame`, with the header as the names of the columns, or
+    /// names by position like `column_1` for a table without a header.
+    ///
+    /// Columns where every cell is a number become a decimal column with the largest scale of
+    /// the numbers, columns of booleans become a boolean column and other columns become a string
+    /// column, with dates as ISO 8601 text.  Empty cells are null.  A column with both numbers
+    /// and text is an error, since it cannot be represented without losing the numbers or the
+    /// text.  Money is text like `USD 1.50`, since a decimal column cannot hold its currency.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use polars::prelude::DataType;
+    /// use tablefi::Table;
+    ///
+    /// let mut table: Table = Table::try_from(r#"[["name","amount"],["a","1.5"],["b","$2.25"]]"#).unwrap();
+    /// table.set_header(true);
+    /// let df = table.to_dataframe().unwrap();
+    /// assert_eq!(df.column("amount").unwrap().dtype(), &DataType::Decimal(38, 2));
+    /// assert_eq!(Table::from(&df).to_string(), r#"[["name","amount"],["a","1.50"],["b","2.25"]]"#);
+    /// ```
+    pub fn to_dataframe(&self) -> Result<DataFrame, DataFrameError> {
+        let header = self.header();
+        let mut columns: Vec<Column> = Vec::new();
+        for c in 0..self.cols() {
+            let col: Vec<Cell> = self.col(c).unwrap_or_default().into_iter().collect();
+            let name = match &header {
+                Some(header) => header.cell(c).to_string(),
+                None => format!("column_{}", c + 1),
+            };
+            let cells = col.get(self.body_start()..).unwrap_or_default();
+            let values: Vec<&Cell> = cells.iter().filter(|cell| !cell.to_string().is_empty()).collect();
+            let numbers = values.iter().filter(|cell| cell.is_number()).count();
+            let column = match (numbers, values.len() - numbers) {
+                (0, bools) if bools > 0 && values.iter().all(|cell| cell.is_bool()) => {
+                    let bools: Vec<Option<bool>> = cells.iter()
+                        .map(|cell| match cell {
+                            Cell::Bool(b) => Some(*b),
+                            _ => None,
+                        })
+                        .collect();
+                    Column::new(name.into(), bools)
+                }
+                (0, _) => {
+                    let strings: Vec<Option<String>> = cells.iter()
+                        .map(|cell| Some(cell.to_string()).filter(|s| !s.is_empty()))
+                        .collect();
+                    Column::new(name.into(), strings)
+                }
+                (_, 0) => {
+                    let scale = values.iter().filter_map(|cell| cell.to_decimal()).map(|d| d.scale()).max().unwrap_or(0);
+                    let mantissas = cells.iter()
+                        .map(|cell| cell.to_decimal().map(|d| {
+                            10_i128.checked_pow(scale - d.scale())
+                                .and_then(|factor| d.mantissa().checked_mul(factor))
+                                .ok_or_else(|| DataFrameError::Overflow(name.clone()))
+                        }).transpose())
+                        .collect::<Result<Vec<Option<i128>>, DataFrameError>>()?;
+                    Int128Chunked::from_iter_options(name.as_str().into(), mantissas.into_iter())
+                        .into_decimal(MAX_PRECISION, scale as usize)?
+                        .into_column()
+                }
+                _ => return Err(DataFrameError::MixedColumn(name)),
+            };
+            columns.push(column);
+        }
+        Ok(DataFrame::new_infer_height(columns)?)
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use polars::prelude::DataType;
+    use super::*;
+
+    #[test]
+    fn test_to_dataframe() {
+        let mut table: Table = Table::try_from(r#"[["id","name","amount"],["1","a","(2.50)"],["2","","12.5%"],["3","c",""]]"#).unwrap();
+        table.set_header(true);
+        let df = table.to_dataframe().unwrap();
+        assert_eq!(df.height(), 3);
+        let dtypes: Vec<&DataType> = df.columns().iter().map(|column| column.dtype()).collect();
+        assert_eq!(dtypes, vec![&DataType::Decimal(38, 0), &DataType::String, &DataType::Decimal(38, 3)]);
+        assert_eq!(df.column("name").unwrap().null_count(), 1);
+        assert_eq!(df.column("amount").unwrap().get(0).unwrap(), AnyValue::Decimal(-2500, 38, 3));
+        let mut table: Table = Table::try_from(r#"[["active","date"],[true,"2024-01-31"],[null,""]]"#).unwrap();
+        table.set_header(true);
+        let df = table.to_dataframe().unwrap();
+        assert_eq!(df.column("active").unwrap().dtype(), &DataType::Boolean);
+        assert_eq!(df.column("date").unwrap().dtype(), &DataType::String);
+        assert_eq!(Table::from(&df).to_string(), table.to_string());
+        assert_eq!(Table::from(&df).cell(1, 0), Some(Cell::Bool(true)));
+        let mut table: Table = Table::try_from(r#"[["a"],["1"],["x"]]"#).unwrap();
+        table.set_header(true);
+        assert!(matches!(table.to_dataframe(), Err(DataFrameError::MixedColumn(name)) if name == "a"));
+        let mut table: Table = Table::try_from(r#"[["a","a"],["1","2"]]"#).unwrap();
+        table.set_header(true);
+        assert!(matches!(table.to_dataframe(), Err(DataFrameError::Polars(_))));
+        // a table without a header has columns named by position
+        let table: Table = Table::try_from(r#"[["1","a"],["2","b"]]"#).unwrap();
+        let df = table.to_dataframe().unwrap();
+        assert_eq!(df.height(), 2);
+        assert_eq!(df.column("column_1").unwrap().dtype(), &DataType::Decimal(38, 0));
+        assert_eq!(df.column("column_2").unwrap().dtype(), &DataType::String);
+        let table: Table = Table::try_from(r#"[["79228162514264337593543950335"],["0.0000000000001"]]"#).unwrap();
+        assert!(matches!(table.to_dataframe(), Err(DataFrameError::Overflow(_))));
+    }
+
+    #[test]
+    fn test_from_dataframe() {
+        let df = DataFrame::new_infer_height(vec![
+            Column::new("id".into(), vec![1i64, 2]),
+            Column::new("rate".into(), vec![Some(0.1f64), None]),
+            Column::new("active".into(), vec![Some(true), None]),
+            Column::new("name".into(), vec![Some("a"), Some("00123")]),
+        ]).unwrap();
+        let table = Table::from(&df);
+        assert_eq!(table.to_string(), r#"[["id","rate","active","name"],["1","0.1","true","a"],["2","","","00123"]]"#);
+        assert_eq!(table.cell(1, 1), Some(Cell::Number(Decimal::new(1, 1))));
+        assert_eq!(table.cell(2, 3), Some(Cell::Text("00123".to_string())));
+        assert_eq!(table.cell(1, 2), Some(Cell::Bool(true)));
+        let mut table: Table = Table::try_from(r#"[["amount"],["$1,234.50"],["-3"]]"#).unwrap();
+        table.set_header(true);
+        assert_eq!(Table::from(&table.to_dataframe().unwrap()).to_string(), r#"[["amount"],["1234.50"],["-3.00"]]"#);
+    }
+
+}
@@ -0,0 +1,252 @@
+use unicode_width::UnicodeWidthStr;
+use super::cell::Cell;
+use super::table::Table;
+
+/// The lines drawn around and between the cells of a rendered table.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum BorderStyle {
+    /// Borders drawn with `+`, `-` and `|`.
+    #[default]
+    Ascii,
+    /// Borders drawn with unicode box drawing characters.
+    Box,
+    /// No borders, with columns separated by two spaces.
+    None,
+}
+
+/// The characters used to draw a border style.
+struct Border {
+    /// The left, junction and right characters of the top, header and bottom lines.
+    corners: [[&'static str; 3]; 3],
+    /// The horizontal line character.
+    horizontal: &'static str,
+    /// The vertical line character.
+    vertical: &'static str,
+}
+
+impl BorderStyle {
+
+    /// Returns the characters for the border style, if borders are drawn.
+    fn border(&self) -> Option<Border> {
+        match self {
+            BorderStyle::Ascii => Some(Border {
+                corners: [["+", "+", "+"], ["+", "+", "+"], ["+", "+", "+"]],
+                horizontal: "-",
+                vertical: "|",
+            }),
+            BorderStyle::Box => Some(Border {
+                corners: [["┌", "┬", "┐"], ["├", "┼", "┤"], ["└", "┴", "┘"]],
+                horizontal: "─",
+                vertical: "│",
+            }),
+            BorderStyle::None => None,
+        }
+    }
+
+}
+
+/// Options used to render a `Table` as text for logs and terminals.
+///
+/// # Examples
+///
+/// ```
+/// use tablefi::{BorderStyle, RenderOptions, Table};
+///
+/// let table: Table = Table::try_from(r#"[["item","amount"],["coffee","3.5"],["rent","$1,200.00"]]"#).unwrap();
+/// let options = RenderOptions { border: BorderStyle::Box, ..Default::default() };
+/// assert_eq!(table.render(&options), "\
+/// ┌────────┬───────────┐
+/// │ item   │ amount    │
+/// ├────────┼───────────┤
+/// │ coffee │      3.5  │
+/// │ rent   │ $1,200.00 │
+/// └────────┴───────────┘
+/// ");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RenderOptions {
+    /// The lines drawn around and between the cells.
+    pub border: BorderStyle,
+    /// Whether the first row is a header, separated from the other rows by a line.  The header of
+    /// a table with `Table::has_header` is always separated.
+    pub header: bool,
+}
+
+impl Default for RenderOptions {
+
+    fn default() -> Self {
+        RenderOptions {
+            border: BorderStyle::Ascii,
+            header: true,
+        }
+    }
+
+}
+
+/// Splits the presentation of a number into the text before and from the decimal separator, so
+/// numbers in a column can be aligned on the decimal separator.
+fn split_decimal(cell: &Cell, s: &str) -> (String, String) {
+    let separator = cell.format().map(|format| format.decimal_separator).unwrap_or('.');
+    let position = s.char_indices()
+        .zip(s.chars().skip(1))
+        .filter(|((_, c), next)| *c == separator && next.is_ascii_digit())
+        .map(|((i, _), _)| i)
+        .last();
+    match position {
+        Some(i) => (s[..i].to_string(), s[i..].to_string()),
+        None => (s.to_string(), String::new()),
+    }
+}
+
+/// Returns the text of a cell on a single line.
+fn single_line(cell: &Cell) -> String {
+    cell.to_string().replace("\r\n", " ").replace(['\r', '\n', '\t'], " ")
+}
+
+/// Pads text with spaces to the display width.
+fn pad(s: &str, width: usize, right: bool) -> String {
+    let padding = " ".repeat(width.saturating_sub(s.width()));
+    match right {
+        true => format!("{}{}", padding, s),
+        false => format!("{}{}", s, padding),
+    }
+}
+
+impl Table {
+
+    /// Renders the table as text with aligned columns.
+    ///
+    /// Column widths are measured in unicode display width, so wide characters like `日本` line
+    /// up in a terminal.  Numbers and money are right aligned on the decimal separator and text is
+    /// left aligned.  Line breaks in cells are rendered as spaces.
+    ///
+    /// Unlike `to_string`, which formats the table as json, the rendered text is meant to be read
+    /// rather than parsed.
+    pub fn render(&self, options: &RenderOptions) -> String {
+        let rows: Vec<Vec<(Cell, String)>> = (0..self.rows())
+            .map(|r| self.row(r).unwrap_or_default().into_iter().map(|cell| {
+                let s = single_line(&cell);
+                (cell, s)
+            }).collect())
+            .collect();
+        // the widths of the integer and fractional parts of the numbers in each column
+        let mut integers = vec![0; self.cols()];
+        let mut fractions = vec![0; self.cols()];
+        let mut widths = vec![0; self.cols()];
+        for row in &rows {
+            for (c, (cell, s)) in row.iter().enumerate() {
+                if cell.is_numeric() {
+                    let (integer, fraction) = split_decimal(cell, s);
+                    integers[c] = integers[c].max(integer.width());
+                    fractions[c] = fractions[c].max(fraction.width());
+                }
+                widths[c] = widths[c].max(s.width());
+            }
+        }
+        for c in 0..self.cols() {
+            widths[c] = widths[c].max(integers[c] + fractions[c]);
+        }
+        let border = options.border.border();
+        let line = |border: &Border, corners: usize| -> String {
+            let [left, junction, right] = border.corners[corners];
+            let segments: Vec<String> = widths.iter().map(|width| border.horizontal.repeat(width + 2)).collect();
+            format!("{}{}{}\n", left, segments.join(junction), right)
+        };
+        let mut text = String::new();
+        if rows.is_empty() {
+            return text;
+        }
+        if let Some(border) = &border {
+            text.push_str(&line(border, 0));
+        }
+        let header = options.header || self.has_header();
+        for (r, row) in rows.iter().enumerate() {
+            let cells: Vec<String> = row.iter()
+                .enumerate()
+                .map(|(c, (cell, s))| match cell.is_numeric() {
+                    true => {
+                        let (integer, fraction) = split_decimal(cell, s);
+                        let aligned = format!("{}{}", pad(&integer, integers[c], true), pad(&fraction, fractions[c], false));
+                        pad(&aligned, widths[c], true)
+                    }
+                    false => pad(s, widths[c], false),
+                })
+                .collect();
+            match &border {
+                Some(border) => {
+                    let separator = format!(" {} ", border.vertical);
+                    text.push_str(&format!("{} {} {}\n", border.vertical, cells.join(&separator), border.vertical));
+                }
+                None => text.push_str(&format!("{}\n", cells.join("  ").trim_end())),
+            }
+            if r == 0 && header && rows.len() > 1 {
+                match &border {
+                    Some(border) => text.push_str(&line(border, 1)),
+                    None => {
+                        let segments: Vec<String> = widths.iter().map(|width| "-".repeat(*width)).collect();
+                        text.push_str(&format!("{}\n", segments.join("  ")));
+                    }
+                }
+            }
+        }
+        if let Some(border) = &border {
+            text.push_str(&line(border, 2));
+        }
+        text
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_decimal() {
+        assert_eq!(split_decimal(&Cell::from("12.50"), "12.50"), ("12".to_string(), ".50".to_string()));
+        assert_eq!(split_decimal(&Cell::from("12"), "12"), ("12".to_string(), String::new()));
+        assert_eq!(split_decimal(&Cell::from("$1,234.5"), "$1,234.5"), ("$1,234".to_string(), ".5".to_string()));
+        assert_eq!(split_decimal(&Cell::from("(2.50)"), "(2.50)"), ("(2".to_string(), ".50)".to_string()));
+        let cell = Cell::parse("1.234,50 €", &super::super::cell::ParseOptions::german());
+        assert_eq!(split_decimal(&cell, &cell.to_string()), ("1.234".to_string(), ",50 €".to_string()));
+    }
+
+    #[test]
+    fn test_render() {
+        assert_eq!(Table::new().render(&RenderOptions::default()), "");
+        let table: Table = Table::try_from(r#"[["name","total"],["日本","12.5"],["a\nb","-3"],["c","n/a"]]"#).unwrap();
+        assert_eq!(table.render(&RenderOptions::default()), "\
++------+-------+
+| name | total |
++------+-------+
+| 日本 |  12.5 |
+| a b  |  -3   |
+| c    | n/a   |
++------+-------+
+");
+        let options = RenderOptions { border: BorderStyle::None, header: true };
+        assert_eq!(table.render(&options), "\
+name  total
+----  -----
+日本   12.5
+a b    -3
+c     n/a
+");
+        let options = RenderOptions { border: BorderStyle::Box, header: false };
+        assert_eq!(table.render(&options), "\
+┌──────┬───────┐
+│ name │ total │
+│ 日本 │  12.5 │
+│ a b  │  -3   │
+│ c    │ n/a   │
+└──────┴───────┘
+");
+        let mut table = table;
+        table.set_header(true);
+        assert!(table.render(&options).contains("├──────┼───────┤"));
+        let table: Table = Table::try_from(r#"[["USD 1.5"],["JPY 1200"]]"#).unwrap();
+        let options = RenderOptions { border: BorderStyle::None, header: false };
+        assert_eq!(table.render(&options), "   USD 1.50\nJPY 1200\n");
+    }
+
+}
@@ -1,7 +1,10 @@
 use rust_decimal::Decimal;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use std::fmt;
 use std::ops::{Add, Div, Mul, Sub};
-use super::cell::Cell;
+use serde_json::value::RawValue;
+use super::cell::{cell_from_field, cell_from_raw, Cell, CellError, ParseOptions};
+use super::json::{JsonCell, JsonStyle};
 
 /// Represents a one-dimensional sequence of `Cell`s, typically a row or a column from a `Table`.
 ///
@@ -57,15 +60,15 @@ impl TryFrom<&str> for Slice {
     type Error = serde_json::Error;
     
     fn try_from(value: &str) -> Result<Self, Self::Error> {
-        serde_json::from_str(value)
+        Slice::from_json_with(value, &ParseOptions::default())
     }
 
 }
 
-impl ToString for Slice {
+impl fmt::Display for Slice {
 
-    fn to_string(&self) -> String {
-        serde_json::to_string(self).unwrap()
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", serde_json::to_string(self).map_err(|_| fmt::Error)?)
     }
 
 }
@@ -190,11 +193,10 @@ impl Add<&Slice> for &Slice {
     type Output = Slice;
 
     fn add(self, other: &Slice) -> Slice {
-        let default_value = Decimal::from(0);
         let mut new_cells: Vec<Cell> = self.cells.clone();
         for (i, cell) in new_cells.iter_mut().enumerate() {
             if let Some(other_cell) = other.cells.get(i) {
-                cell.add_value(other_cell.to_decimal().unwrap_or(default_value));
+                *cell = &*cell + other_cell;
             }
         }
         Slice { cells: new_cells }
@@ -207,11 +209,10 @@ impl Sub<&Slice> for &Slice {
     type Output = Slice;
 
     fn sub(self, other: &Slice) -> Slice {
-        let default_value = Decimal::from(0);
         let mut new_cells: Vec<Cell> = self.cells.clone();
         for (i, cell) in new_cells.iter_mut().enumerate() {
             if let Some(other_cell) = other.cells.get(i) {
-                cell.sub_value(other_cell.to_decimal().unwrap_or(default_value));
+                *cell = &*cell - other_cell;
             }
         }
         Slice { cells: new_cells }
@@ -224,11 +225,10 @@ impl Mul<&Slice> for &Slice {
     type Output = Slice;
 
     fn mul(self, other: &Slice) -> Slice {
-        let default_value = Decimal::from(1);
         let mut new_cells: Vec<Cell> = self.cells.clone();
         for (i, cell) in new_cells.iter_mut().enumerate() {
             if let Some(other_cell) = other.cells.get(i) {
-                cell.mul_value(other_cell.to_decimal().unwrap_or(default_value));
+                *cell = &*cell * other_cell;
             }
         }
         Slice { cells: new_cells }
@@ -241,11 +241,10 @@ impl Div<&Slice> for &Slice {
     type Output = Slice;
 
     fn div(self, other: &Slice) -> Slice {
-        let default_value = Decimal::from(1);
         let mut new_cells: Vec<Cell> = self.cells.clone();
         for (i, cell) in new_cells.iter_mut().enumerate() {
             if let Some(other_cell) = other.cells.get(i) {
-                cell.div_value(other_cell.to_decimal().unwrap_or(default_value));
+                *cell = &*cell / other_cell;
             }
         }
         Slice { cells: new_cells }
@@ -255,6 +254,52 @@ impl Div<&Slice> for &Slice {
 
 impl Slice {
 
+    /// Parses text into a slice of cells using the specified options.
+    ///
+    /// Column inference overrides in the options apply to the cells by position.
+    ///
+    /// # Examples
+    /// ```
+    /// use tablefi::{Inference, ParseOptions, Slice};
+    ///
+    /// let mut options = ParseOptions::default();
+    /// options.column_inference.insert(0, Inference::AlwaysText);
+    /// let slice = Slice::parse(vec!["00123", "00123"], &options);
+    /// assert_eq!(slice.to_string(), r#"["00123","123"]"#);
+    /// ```
+    pub fn parse<I: IntoIterator<Item = S>, S: AsRef<str>>(iter: I, options: &ParseOptions) -> Slice {
+        // without strict parsing, cells are never an error
+        let options = ParseOptions { strict: false, ..options.clone() };
+        Slice::try_parse(iter, &options).unwrap_or_default()
+    }
+
+    /// Parses text into a slice of cells using the specified options, failing on values that cannot
+    /// be parsed as requested if the options are strict.
+    pub fn try_parse<I: IntoIterator<Item = S>, S: AsRef<str>>(iter: I, options: &ParseOptions) -> Result<Slice, String> {
+        iter.into_iter()
+            .enumerate()
+            .map(|(i, s)| cell_from_field(s.as_ref(), options, i))
+            .collect::<Result<Vec<Cell>, String>>()
+            .map(Slice::from)
+    }
+
+    /// Parses a slice from a json array, using the specified options for text values.
+    pub fn from_json_with(value: &str, options: &ParseOptions) -> Result<Slice, serde_json::Error> {
+        let values: Vec<&RawValue> = serde_json::from_str(value)?;
+        values.into_iter()
+            .enumerate()
+            .map(|(i, val)| cell_from_raw(val, options, i))
+            .collect::<Result<Vec<Cell>, String>>()
+            .map(Slice::from)
+            .map_err(serde::de::Error::custom)
+    }
+
+    /// Formats the slice as a json array in the specified style.
+    pub fn to_json_with(&self, style: JsonStyle) -> Result<String, serde_json::Error> {
+        let cells: Vec<JsonCell> = self.cells.iter().map(|cell| JsonCell(cell, style)).collect();
+        serde_json::to_string(&cells)
+    }
+
     /// Provides an immutable reference to the underlying vector of `Cell`s.
     fn cells(&self) -> &Vec<Cell> {
         &self.cells
@@ -265,6 +310,11 @@ impl Slice {
         self.cells().len()
     }
 
+    /// Whether the slice contains no cells.
+    pub fn is_empty(&self) -> bool {
+        self.cells().is_empty()
+    }
+
     /// Retrieves a `Cell` at the specified index.
     pub fn cell(&self, idx: usize) -> Cell {
         self.cells()[idx].clone()
@@ -299,7 +349,7 @@ impl Slice {
         self
     }
 
-    /// Divides value from all numerical cells in the slice.  A value of `0` will result in `#DIV/O`.
+    /// Divides value from all numerical cells in the slice.  A value of `0` will result in `#DIV/0!`.
     pub fn div_value(&mut self, value: Decimal) -> &mut Self {
         for cell in self.cells.iter_mut() {
             cell.div_value(value);
@@ -307,6 +357,83 @@ impl Slice {
         self
     }
 
+    /// Applies a checked operation to the cells of both slices, pairing cells by index.  Cells
+    /// without a pair in the other slice are unchanged.
+    fn checked_zip(&self, other: &Slice, op: fn(&Cell, &Cell) -> Result<Cell, CellError>) -> Result<Slice, CellError> {
+        let mut new_cells: Vec<Cell> = self.cells.clone();
+        for (cell, other_cell) in new_cells.iter_mut().zip(other.cells.iter()) {
+            *cell = op(cell, other_cell)?;
+        }
+        Ok(Slice { cells: new_cells })
+    }
+
+    /// Applies a checked operation to every cell, leaving the slice unchanged when an error is
+    /// returned.
+    fn checked_apply(&mut self, value: Decimal, op: fn(&mut Cell, Decimal) -> Result<(), CellError>) -> Result<&mut Self, CellError> {
+        let mut new_cells: Vec<Cell> = self.cells.clone();
+        for cell in new_cells.iter_mut() {
+            op(cell, value)?;
+        }
+        self.cells = new_cells;
+        Ok(self)
+    }
+
+    /// Adds the cells of another slice, like `Cell::checked_add`.
+    ///
+    /// Returns the first error, either an error held by a cell or `CellError::Overflow`.
+    ///
+    /// # Examples
+    /// ```
+    /// use tablefi::{CellError, Slice};
+    ///
+    /// let slice1: Slice = Slice::try_from(r#"["1","79228162514264337593543950335"]"#).unwrap();
+    /// let slice2: Slice = Slice::try_from(r#"["2","1"]"#).unwrap();
+    /// assert_eq!(slice1.checked_add(&slice2).unwrap_err(), CellError::Overflow);
+    /// assert_eq!((&slice1 + &slice2).to_string(), r##"["3","#NUM!"]"##);
+    /// ```
+    pub fn checked_add(&self, other: &Slice) -> Result<Slice, CellError> {
+        self.checked_zip(other, Cell::checked_add)
+    }
+
+    /// Subtracts the cells of another slice, like `Cell::checked_sub`.
+    pub fn checked_sub(&self, other: &Slice) -> Result<Slice, CellError> {
+        self.checked_zip(other, Cell::checked_sub)
+    }
+
+    /// Multiplies the cells of another slice, like `Cell::checked_mul`.
+    pub fn checked_mul(&self, other: &Slice) -> Result<Slice, CellError> {
+        self.checked_zip(other, Cell::checked_mul)
+    }
+
+    /// Divides the cells of another slice, like `Cell::checked_div`.
+    pub fn checked_div(&self, other: &Slice) -> Result<Slice, CellError> {
+        self.checked_zip(other, Cell::checked_div)
+    }
+
+    /// Adds value to all numerical cells in the slice, like `Cell::checked_add_value`.  The slice
+    /// is unchanged when an error is returned.
+    pub fn checked_add_value(&mut self, value: Decimal) -> Result<&mut Self, CellError> {
+        self.checked_apply(value, Cell::checked_add_value)
+    }
+
+    /// Subtracts value from all numerical cells in the slice, like `Cell::checked_sub_value`.  The
+    /// slice is unchanged when an error is returned.
+    pub fn checked_sub_value(&mut self, value: Decimal) -> Result<&mut Self, CellError> {
+        self.checked_apply(value, Cell::checked_sub_value)
+    }
+
+    /// Multiplies value to all numerical cells in the slice, like `Cell::checked_mul_value`.  The
+    /// slice is unchanged when an error is returned.
+    pub fn checked_mul_value(&mut self, value: Decimal) -> Result<&mut Self, CellError> {
+        self.checked_apply(value, Cell::checked_mul_value)
+    }
+
+    /// Divides value from all numerical cells in the slice, like `Cell::checked_div_value`.  The
+    /// slice is unchanged when an error is returned.
+    pub fn checked_div_value(&mut self, value: Decimal) -> Result<&mut Self, CellError> {
+        self.checked_apply(value, Cell::checked_div_value)
+    }
+
     /// Returns an iterator over the cells in the slice.
     pub fn iter(&self) -> std::slice::Iter<'_, Cell> {
         self.cells.iter()
@@ -318,6 +445,7 @@ impl Slice {
     }
 
     /// Consumes the `Slice` and returns an iterator over its `Cell`s.
+    #[allow(clippy::should_implement_trait)]
     pub fn into_iter(self) -> std::vec::IntoIter<Cell> {
         self.cells.into_iter()
     }
@@ -328,7 +456,7 @@ impl Slice {
     fn _find_value<T: ?Sized>(&self, other_value: &T, max: Option<usize>) -> Vec<usize> where for<'r> &'r T: Into<Cell> {
         let mut vec: Vec<usize> = Vec::new();
         for (i, cell) in self.cells.iter().enumerate() {
-            if cell.equal_value(&other_value) {
+            if cell.equal_value(other_value) {
                 vec.push(i);
                 if vec.len() == max.unwrap_or(usize::MAX) {
                     return vec;
@@ -345,14 +473,16 @@ impl Slice {
 
     /// Whether the value exists in the `Slice`.
     pub fn contains_value<T: ?Sized>(&self, other_value: &T) -> bool where for<'r> &'r T: Into<Cell> {
-        self._find_value(other_value, Some(1)).len() > 0
+        !self._find_value(other_value, Some(1)).is_empty()
     }
 
 }
 
 #[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
 mod tests {
     use super::*;
+    use super::super::cell::Inference;
 
     #[test]
     fn test_string() {
@@ -381,6 +511,24 @@ mod tests {
         assert_eq!(slice.cell(2).to_string(), "1".to_string());
         assert_eq!(slice.cell(2).to_decimal(), Some(Decimal::from(1)));
         assert_eq!(slice.to_string(), r#"["a","b","1"]"#);
+        assert_eq!(slice.to_json_with(JsonStyle::Typed).unwrap(), r#"["a","b",1]"#);
+        let slice = Slice::try_from("[99999999999999999999.99]").unwrap();
+        assert_eq!(slice.cell(0), Cell::Number(Decimal::from_i128_with_scale(9999999999999999999999, 2)));
+    }
+
+    #[test]
+    fn test_parse() {
+        let options = ParseOptions { inference: Inference::AlwaysText, ..Default::default() };
+        assert_eq!(Slice::parse(vec!["00123", "1,234"], &options).to_string(), r#"["00123","1,234"]"#);
+        let mut options = ParseOptions::default();
+        options.column_inference.insert(1, Inference::AlwaysNumber);
+        assert_eq!(Slice::parse(vec!["a", "b"], &options).to_string(), r#"["a","b"]"#);
+        options.strict = true;
+        assert!(Slice::try_parse(vec!["a", "b"], &options).is_err());
+        assert_eq!(Slice::try_parse(vec!["a", "1"], &options).unwrap().cell(1).to_decimal(), Some(Decimal::from(1)));
+        let slice = Slice::from_json_with(r#"["00123",123]"#, &ParseOptions { inference: Inference::AlwaysText, ..Default::default() }).unwrap();
+        assert_eq!(slice.cell(0), Cell::Text("00123".to_string()));
+        assert_eq!(slice.cell(1), Cell::Text("123".to_string()));
     }
 
     #[test]
@@ -406,6 +554,9 @@ mod tests {
         assert_eq!(slice5.to_string(), r#"["5","a","9"]"#);
         slice5.add_value(Decimal::from(1));
         assert_eq!(slice5.to_string(), r#"["6","a","10"]"#);
+        let slice6: Slice = Slice::try_from(r#"["$1,000.00","(2.50)","5%"]"#).unwrap();
+        let slice7 = &slice6 + &slice1;
+        assert_eq!(slice7.to_string(), r#"["$1,001.00","(0.50)","305%"]"#);
     }
 
     #[test]
@@ -458,7 +609,38 @@ mod tests {
         slice5.div_value(Decimal::from(2));
         assert_eq!(slice5.to_string(), r#"["2","a","1"]"#);
         slice5.div_value(Decimal::from(0));
-        assert_eq!(slice5.to_string(), r##"["#DIV/0","a","#DIV/0"]"##);
+        assert_eq!(slice5.to_string(), r##"["#DIV/0!","a","#DIV/0!"]"##);
+        // errors propagate
+        let slice6 = &slice1 / &Slice::try_from(r#"["0","1","x"]"#).unwrap();
+        assert_eq!(slice6.to_string(), r##"["#DIV/0!","2","3"]"##);
+        let slice7 = &(&slice6 + &slice1) * &slice1;
+        assert_eq!(slice7.to_string(), r##"["#DIV/0!","8","18"]"##);
+        assert!(slice7.cell(0).is_divide_by_zero());
+    }
+
+    #[test]
+    fn test_checked() {
+        let slice1: Slice = Slice::try_from(r#"["1","79228162514264337593543950335","a"]"#).unwrap();
+        let slice2: Slice = Slice::try_from(r#"["2","1"]"#).unwrap();
+        assert_eq!(slice1.checked_add(&slice2).unwrap_err(), CellError::Overflow);
+        assert_eq!((&slice1 + &slice2).to_string(), r##"["3","#NUM!","a"]"##);
+        assert_eq!(slice1.checked_sub(&slice2).unwrap().to_string(), r#"["-1","79228162514264337593543950334","a"]"#);
+        assert_eq!(slice1.checked_mul(&slice2).unwrap().to_string(), r#"["2","79228162514264337593543950335","a"]"#);
+        assert_eq!(slice1.checked_div(&Slice::try_from(r#"["0"]"#).unwrap()).unwrap_err(), CellError::DivZero);
+        let mut slice3 = slice1.clone();
+        assert_eq!(slice3.checked_add_value(Decimal::ONE).unwrap_err(), CellError::Overflow);
+        assert_eq!(slice3.to_string(), slice1.to_string());
+        assert_eq!(slice3.checked_mul_value(Decimal::TWO).unwrap_err(), CellError::Overflow);
+        assert_eq!(slice3.checked_div_value(Decimal::ZERO).unwrap_err(), CellError::DivZero);
+        assert_eq!(slice3.checked_sub_value(Decimal::ONE).unwrap().to_string(), r#"["0","79228162514264337593543950334","a"]"#);
+        slice3.add_value(Decimal::TWO);
+        assert_eq!(slice3.to_string(), r##"["2","#NUM!","a"]"##);
+        assert_eq!(slice3.checked_add_value(Decimal::ONE).unwrap_err(), CellError::Overflow);
+        // mixed currencies
+        let usd: Slice = Slice::try_from(r#"["USD 1","USD 2"]"#).unwrap();
+        let mixed: Slice = Slice::try_from(r#"["EUR 1","USD 2.005"]"#).unwrap();
+        assert_eq!(usd.checked_add(&mixed).unwrap_err(), CellError::Value);
+        assert_eq!((&usd + &mixed).to_string(), r##"["#VALUE!","USD 4.01"]"##);
     }
 
     #[test]
@@ -0,0 +1,260 @@
+use calamine::{open_workbook_auto_from_rs, CellErrorType, Data, Dimensions, Range, Reader, Sheets};
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+use rust_decimal::Decimal;
+use std::io::{Cursor, Error, ErrorKind, Read};
+use std::str::FromStr;
+use super::cell::{Cell, CellError};
+use super::table::Table;
+
+/// Identifies the worksheet read from a spreadsheet.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Sheet {
+    /// The worksheet at a zero based position in the workbook.
+    Index(usize),
+    /// The worksheet with the given name.
+    Name(String),
+}
+
+impl Default for Sheet {
+
+    fn default() -> Self {
+        Sheet::Index(0)
+    }
+
+}
+
+/// Options used to read a `Table` from an xlsx, xls, xlsb or ods spreadsheet.
+///
+/// # Examples
+///
+/// ```
+/// use tablefi::{Sheet, SpreadsheetOptions};
+///
+/// let options = SpreadsheetOptions { sheet: Sheet::Name("Q1".to_string()), ..Default::default() };
+/// assert!(options.expand_merged);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SpreadsheetOptions {
+    /// The worksheet to read.
+    pub sheet: Sheet,
+    /// Whether the value of a merged region is copied to every cell in the region.  Otherwise
+    /// only the top left cell of the region has a value.
+    ///
+    /// Merged regions are only available for xlsx and xls workbooks.
+    pub expand_merged: bool,
+}
+
+impl Default for SpreadsheetOptions {
+
+    fn default() -> Self {
+        SpreadsheetOptions {
+            sheet: Sheet::default(),
+            expand_merged: true,
+        }
+    }
+
+}
+
+/// Converts an ISO 8601 date like `2024-01-31`, or date and time like `2024-01-31T09:30:00`, to a
+/// cell, as ods workbooks report dates.  A date and time at midnight is a date, like a date in an
+/// xlsx workbook.
+fn cell_from_iso(s: &str) -> Option<Cell> {
+    if let Ok(date) = NaiveDate::parse_from_str(s, "%Y-%m-%d") {
+        return Some(Cell::Date(date));
+    }
+    let dt = NaiveDateTime::parse_from_str(s, "%Y-%m-%dT%H:%M:%S%.f").ok()?;
+    Some(match dt.time() == NaiveTime::MIN {
+        true => Cell::Date(dt.date()),
+        false => Cell::DateTime(dt),
+    })
+}
+
+/// Converts a spreadsheet value to a cell.
+///
+/// Text is kept as text without inferring numbers, so values like `00123` entered as text in the
+/// spreadsheet are unchanged.  Durations are kept as text, like `PT12H30M0S` in an ods
+/// workbook.
+fn cell_from_data(data: &Data) -> Cell {
+    match data {
+        Data::Int(i) => Cell::Number(Decimal::from(*i)),
+        // the shortest representation of the float avoids binary noise like 0.1 + 0.2
+        Data::Float(f) => match Decimal::from_str(&f.to_string()) {
+            Ok(d) => Cell::Number(d.normalize()),
+            Err(_) => Cell::Text(f.to_string()),
+        },
+        Data::String(s) => Cell::Text(s.clone()),
+        Data::Bool(b) => Cell::Bool(*b),
+        Data::DateTime(dt) if dt.is_datetime() => {
+            let (y, m, d, h, min, s, milli) = dt.to_ymd_hms_milli();
+            let date = NaiveDate::from_ymd_opt(y.into(), m.into(), d.into());
+            match (date, (h, min, s, milli)) {
+                (Some(date), (0, 0, 0, 0)) => Cell::Date(date),
+                (Some(date), _) => date.and_hms_milli_opt(h.into(), min.into(), s.into(), milli.into())
+                    .map(Cell::DateTime)
+                    .unwrap_or_else(|| Cell::Text(data.to_string())),
+                (None, _) => Cell::Text(data.to_string()),
+            }
+        }
+        Data::DateTimeIso(s) => cell_from_iso(s).unwrap_or_else(|| Cell::Text(s.clone())),
+        Data::Error(e) => match e {
+            CellErrorType::Div0 => Cell::Error(CellError::DivZero),
+            CellErrorType::Value => Cell::Error(CellError::Value),
+            CellErrorType::Ref => Cell::Error(CellError::Ref),
+            CellErrorType::NA => Cell::Error(CellError::NA),
+            CellErrorType::Num => Cell::Error(CellError::Overflow),
+            _ => Cell::Text(data.to_string()),
+        },
+        Data::Empty => Cell::default(),
+        _ => Cell::Text(data.to_string()),
+    }
+}
+
+/// Converts a spreadsheet error to an io error.
+fn spreadsheet_error<E: std::error::Error + Send + Sync + 'static>(e: E) -> Error {
+    Error::other(e)
+}
+
+/// Returns the merged regions of a worksheet, if the format supports them.
+fn merged_regions<RS: Read + std::io::Seek>(workbook: &mut Sheets<RS>, name: &str) -> std::io::Result<Vec<Dimensions>> {
+    match workbook {
+        Sheets::Xlsx(xlsx) => xlsx.worksheet_merge_cells(name)
+            .transpose()
+            .map_err(spreadsheet_error)
+            .map(Option::unwrap_or_default),
+        Sheets::Xls(xls) => Ok(xls.worksheet_merge_cells(name).unwrap_or_default()),
+        _ => Ok(Vec::new()),
+    }
+}
+
+impl Table {
+
+    /// Reads a worksheet from an xlsx, xls, xlsb or ods spreadsheet.
+    ///
+    /// Cells keep their position in the worksheet, so the cell `A1` is always at row `0` and
+    /// column `0`.  Numbers become `Cell::Number`, booleans become `Cell::Bool`, formulas are read
+    /// as their cached values, and dates become `Cell::Date`, or `Cell::DateTime` when they have a
+    /// time.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use std::fs::File;
+    /// use tablefi::{Sheet, SpreadsheetOptions, Table};
+    ///
+    /// let mut file = File::open("statement.xlsx").unwrap();
+    /// let options = SpreadsheetOptions { sheet: Sheet::Name("Transactions".to_string()), ..Default::default() };
+    /// let table = Table::read_spreadsheet(&mut file, &options).unwrap();
+    /// ```
+    pub fn read_spreadsheet<R: Read>(reader: &mut R, options: &SpreadsheetOptions) -> std::io::Result<Table> {
+        let mut buffer: Vec<u8> = Vec::new();
+        reader.read_to_end(&mut buffer)?;
+        let mut workbook = open_workbook_auto_from_rs(Cursor::new(buffer)).map_err(spreadsheet_error)?;
+        let name = match &options.sheet {
+            Sheet::Index(i) => workbook.sheet_names().get(*i).cloned(),
+            Sheet::Name(name) => workbook.sheet_names().into_iter().find(|n| n == name),
+        }.ok_or_else(|| Error::new(ErrorKind::NotFound, format!("Worksheet {:?} not found", options.sheet)))?;
+        let range: Range<Data> = workbook.worksheet_range(&name).map_err(spreadsheet_error)?;
+        let merged = match options.expand_merged {
+            true => merged_regions(&mut workbook, &name)?,
+            false => Vec::new(),
+        };
+        let mut table = Table::new();
+        let Some((end_row, end_col)) = range.end() else {
+            return Ok(table);
+        };
+        for r in 0..=end_row {
+            let row: Vec<Cell> = (0..=end_col)
+                .map(|c| {
+                    // a merged cell has the value of the top left cell of its region
+                    let (r, c) = merged.iter()
+                        .find(|region| region.contains(r, c))
+                        .map(|region| region.start)
+                        .unwrap_or((r, c));
+                    range.get_value((r, c)).map(cell_from_data).unwrap_or_default()
+                })
+                .collect();
+            table.push_row(row);
+        }
+        Ok(table)
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_cell_from_data() {
+        assert_eq!(cell_from_data(&Data::Int(12)), Cell::Number(Decimal::from(12)));
+        assert_eq!(cell_from_data(&Data::Float(0.1)), Cell::Number(Decimal::new(1, 1)));
+        assert_eq!(cell_from_data(&Data::Float(1234.5)), Cell::Number(Decimal::new(12345, 1)));
+        assert_eq!(cell_from_data(&Data::Float(3.0)), Cell::Number(Decimal::from(3)));
+        assert_eq!(cell_from_data(&Data::Float(1e40)), Cell::Text(1e40.to_string()));
+        assert_eq!(cell_from_data(&Data::String("00123".to_string())), Cell::Text("00123".to_string()));
+        assert_eq!(cell_from_data(&Data::Bool(true)), Cell::Bool(true));
+        assert_eq!(cell_from_data(&Data::Empty), Cell::default());
+        assert_eq!(cell_from_data(&Data::Error(CellErrorType::Div0)), Cell::Error(CellError::DivZero));
+        assert_eq!(cell_from_data(&Data::Error(CellErrorType::Name)), Cell::Text("#NAME?".to_string()));
+        // ods dates are ISO 8601 text, read like xlsx dates
+        let date = NaiveDate::from_ymd_opt(2024, 1, 31).unwrap();
+        assert_eq!(cell_from_data(&Data::DateTimeIso("2024-01-31".to_string())), Cell::Date(date));
+        assert_eq!(cell_from_data(&Data::DateTimeIso("2024-01-31T00:00:00".to_string())), Cell::Date(date));
+        assert_eq!(cell_from_data(&Data::DateTimeIso("2024-01-31T09:30:00.5".to_string())), Cell::DateTime(date.and_hms_milli_opt(9, 30, 0, 500).unwrap()));
+        assert_eq!(cell_from_data(&Data::DateTimeIso("soon".to_string())), Cell::Text("soon".to_string()));
+        assert_eq!(cell_from_data(&Data::DurationIso("PT12H30M0S".to_string())), Cell::Text("PT12H30M0S".to_string()));
+    }
+
+    #[test]
+    fn test_read_spreadsheet_invalid() {
+        let mut reader: &[u8] = b"a,b\n1,2\n";
+        assert!(Table::read_spreadsheet(&mut reader, &SpreadsheetOptions::default()).is_err());
+    }
+
+    #[cfg(feature = "xlsx")]
+    #[test]
+    fn test_read_spreadsheet() {
+        use rust_xlsxwriter::{ExcelDateTime, Format, Formula, Workbook};
+
+        let mut workbook = Workbook::new();
+        let worksheet = workbook.add_worksheet();
+        worksheet.set_name("first").unwrap();
+        worksheet.write_string(0, 0, "first").unwrap();
+        let worksheet = workbook.add_worksheet();
+        worksheet.set_name("second").unwrap();
+        worksheet.merge_range(0, 0, 0, 1, "merged", &Format::new()).unwrap();
+        worksheet.write_string(1, 0, "00123").unwrap();
+        worksheet.write_number(1, 1, 1234.56).unwrap();
+        worksheet.write_formula(2, 0, Formula::new("=B2*2").set_result("2469.12")).unwrap();
+        let date = ExcelDateTime::from_ymd(2024, 1, 31).unwrap();
+        worksheet.write_datetime_with_format(2, 1, &date, &Format::new().set_num_format("yyyy-mm-dd")).unwrap();
+        let buffer = workbook.save_to_buffer().unwrap();
+
+        let table = Table::read_spreadsheet(&mut buffer.as_slice(), &SpreadsheetOptions::default()).unwrap();
+        assert_eq!(table.to_string(), r#"[["first"]]"#);
+        let options = SpreadsheetOptions { sheet: Sheet::Name("second".to_string()), ..Default::default() };
+        let table = Table::read_spreadsheet(&mut buffer.as_slice(), &options).unwrap();
+        assert_eq!(table.to_string(), r#"[["merged","merged"],["00123","1234.56"],["2469.12","2024-01-31"]]"#);
+        assert_eq!(table.cell(1, 0), Some(Cell::Text("00123".to_string())));
+        assert_eq!(table.cell(1, 1), Some(Cell::Number(Decimal::new(123456, 2))));
+        assert_eq!(table.cell(2, 0), Some(Cell::Number(Decimal::new(246912, 2))));
+        assert_eq!(table.cell(2, 1), Some(Cell::Date(NaiveDate::from_ymd_opt(2024, 1, 31).unwrap())));
+        let options = SpreadsheetOptions { sheet: Sheet::Index(1), expand_merged: false };
+        let table = Table::read_spreadsheet(&mut buffer.as_slice(), &options).unwrap();
+        assert_eq!(table.row(0).unwrap().to_string(), r#"["merged",""]"#);
+        let options = SpreadsheetOptions { sheet: Sheet::Name("third".to_string()), ..Default::default() };
+        let err = Table::read_spreadsheet(&mut buffer.as_slice(), &options).unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::NotFound);
+        // typed cells written by write_xlsx
+        let table: Table = Table::try_from(r#"[["a",true,"2024-01-31","2024-01-31T09:30:00"],["b","","",""]]"#).unwrap();
+        let mut buffer: Vec<u8> = Vec::new();
+        table.write_xlsx(&mut buffer, &crate::XlsxOptions::default()).unwrap();
+        let read = Table::read_spreadsheet(&mut buffer.as_slice(), &SpreadsheetOptions::default()).unwrap();
+        assert_eq!(read.to_string(), table.to_string());
+        for c in 0..4 {
+            assert_eq!(read.cell(0, c), table.cell(0, c));
+        }
+        assert_eq!(read.cell(1, 1), Some(Cell::Empty));
+    }
+
+}
@@ -0,0 +1,176 @@
+use rusqlite::types::ValueRef;
+use rusqlite::{params_from_iter, Connection};
+use super::cell::{cell_from_field, Cell, ParseOptions};
+use super::table::Table;
+
+/// How `Table::write_sqlite` treats an existing sqlite table.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SqliteMode {
+    /// The sqlite table is created, failing if it already exists.
+    #[default]
+    Create,
+    /// Rows are appended to the sqlite table, which is created if it does not exist.
+    Append,
+    /// The sqlite table is dropped, if it exists, and created again.
+    Replace,
+}
+
+/// Quotes an sqlite identifier, like a table or column name.
+fn quote(name: &str) -> String {
+    format!("\"{}\"", name.replace('"', "\"\""))
+}
+
+impl Table {
+
+    /// Reads a table from the results of an sqlite query, with the names of the result columns
+    /// as the header.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rusqlite::Connection;
+    /// use tablefi::{SqliteMode, Table};
+    ///
+    /// let conn = Connection::open_in_memory().unwrap();
+    /// let mut table: Table = Table::try_from(r#"[["account","balance"],["cash","$1,234.50"],["loan","(20.00)"]]"#).unwrap();
+    /// table.set_header(true);
+    /// table.write_sqlite(&conn, "ledger", SqliteMode::Create).unwrap();
+    /// let table = Table::from_sqlite_query(&conn, "SELECT * FROM ledger ORDER BY account").unwrap();
+    /// assert_eq!(table.to_string(), r#"[["account","balance"],["cash","1234.50"],["loan","-20.00"]]"#);
+    /// ```
+    pub fn from_sqlite_query(conn: &Connection, sql: &str) -> rusqlite::Result<Table> {
+        Table::from_sqlite_query_with(conn, sql, &ParseOptions::default())
+    }
+
+    /// Reads a table from the results of an sqlite query, using the specified options to parse
+    /// text values.
+    ///
+    /// Text values are parsed into cells, so numbers written by `Table::write_sqlite` are read
+    /// as numbers.  Integers and reals become numbers, nulls become empty cells and blobs become
+    /// text.
+    pub fn from_sqlite_query_with(conn: &Connection, sql: &str, options: &ParseOptions) -> rusqlite::Result<Table> {
+        let mut stmt = conn.prepare(sql)?;
+        let mut table = Table::with_header(stmt.column_names());
+        let cols = stmt.column_count();
+        let mut rows = stmt.query([])?;
+        while let Some(row) = rows.next()? {
+            let mut cells: Vec<Cell> = Vec::with_capacity(cols);
+            for c in 0..cols {
+                let cell = match row.get_ref(c)? {
+                    ValueRef::Null => Cell::default(),
+                    ValueRef::Integer(i) => Cell::Number(i.into()),
+                    ValueRef::Real(f) => Cell::parse(&f.to_string(), &ParseOptions::default()),
+                    ValueRef::Text(s) => cell_from_field(&String::from_utf8_lossy(s), options, c)
+                        .map_err(|e| rusqlite::Error::FromSqlConversionFailure(c, rusqlite::types::Type::Text, e.into()))?,
+                    ValueRef::Blob(b) => Cell::Text(String::from_utf8_lossy(b).to_string()),
+                };
+                cells.push(cell);
+            }
+            table.push_row(cells);
+        }
+        Ok(table)
+    }
+
+    /// Writes the table to an sqlite table, with the header as the names of the columns.  A table
+    /// without a header has its columns named by position, like `column_1`, and every row is
+    /// written.  Writing a table without columns fails.
+    ///
+    /// Every column is declared as `TEXT`, so sqlite does not convert numbers to lossy `REAL`
+    /// values.  Numbers are stored as their exact decimal value, like `-20.00` for `(20.00)`,
+    /// money is stored as text with its currency, like `USD 1.50`, text is stored as is and empty
+    /// cells are stored as `NULL`.  When appending, values are
+    /// inserted into the columns with the same names.  The rows are written in a single
+    /// transaction.
+    pub fn write_sqlite(&self, conn: &Connection, table_name: &str, mode: SqliteMode) -> rusqlite::Result<()> {
+        if self.cols() == 0 {
+            let message = format!("Table without columns cannot be written to sqlite table {}", table_name);
+            return Err(rusqlite::Error::SqliteFailure(rusqlite::ffi::Error::new(rusqlite::ffi::SQLITE_MISUSE), Some(message)));
+        }
+        let names: Vec<String> = match self.header() {
+            Some(header) => header.iter().map(|cell| quote(&cell.to_string())).collect(),
+            None => (1..=self.cols()).map(|c| quote(&format!("column_{}", c))).collect(),
+        };
+        let tx = conn.unchecked_transaction()?;
+        if mode == SqliteMode::Replace {
+            tx.execute(&format!("DROP TABLE IF EXISTS {}", quote(table_name)), [])?;
+        }
+        let exists = match mode {
+            SqliteMode::Append => " IF NOT EXISTS",
+            SqliteMode::Create | SqliteMode::Replace => "",
+        };
+        let columns: Vec<String> = names.iter().map(|name| format!("{} TEXT", name)).collect();
+        tx.execute(&format!("CREATE TABLE{} {} ({})", exists, quote(table_name), columns.join(", ")), [])?;
+        let placeholders = vec!["?"; names.len()].join(", ");
+        {
+            let mut stmt = tx.prepare(&format!("INSERT INTO {} ({}) VALUES ({})", quote(table_name), names.join(", "), placeholders))?;
+            for r in self.body_start()..self.rows() {
+                let values: Vec<Option<String>> = self.row(r).unwrap_or_default().iter()
+                    .map(|cell| match cell.to_decimal().filter(|_| cell.is_number()) {
+                        Some(d) => Some(d.to_string()),
+                        None => Some(cell.to_string()).filter(|s| !s.is_empty()),
+                    })
+                    .collect();
+                stmt.execute(params_from_iter(values))?;
+            }
+        }
+        tx.commit()
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use rust_decimal::Decimal;
+    use super::*;
+    use super::super::cell::Inference;
+
+    #[test]
+    fn test_quote() {
+        assert_eq!(quote("a"), "\"a\"");
+        assert_eq!(quote("a\"b"), "\"a\"\"b\"");
+    }
+
+    #[test]
+    fn test_sqlite() {
+        let conn = Connection::open_in_memory().unwrap();
+        let mut table: Table = Table::try_from(r#"[["id","name","amount"],["00123","a","0.1"],["2","","12.5%"]]"#).unwrap();
+        table.set_header(true);
+        table.write_sqlite(&conn, "t", SqliteMode::Create).unwrap();
+        assert!(table.write_sqlite(&conn, "t", SqliteMode::Create).is_err());
+        let typeof_amount: String = conn.query_row("SELECT typeof(amount) FROM t LIMIT 1", [], |row| row.get(0)).unwrap();
+        assert_eq!(typeof_amount, "text");
+        let nulls: i64 = conn.query_row("SELECT count(*) FROM t WHERE name IS NULL", [], |row| row.get(0)).unwrap();
+        assert_eq!(nulls, 1);
+        let table = Table::from_sqlite_query(&conn, "SELECT * FROM t").unwrap();
+        assert_eq!(table.to_string(), r#"[["id","name","amount"],["123","a","0.1"],["2","","0.125"]]"#);
+        assert_eq!(table.col_by_name("amount").unwrap().to_string(), r#"["0.1","0.125"]"#);
+        assert_eq!(table.cell(1, 2), Some(Cell::Number(Decimal::new(1, 1))));
+        let mut options = ParseOptions::default();
+        options.column_inference.insert(0, Inference::AlwaysText);
+        let table = Table::from_sqlite_query_with(&conn, "SELECT id, amount * 2 AS twice, 3 AS n FROM t", &options).unwrap();
+        assert_eq!(table.to_string(), r#"[["id","twice","n"],["123","0.2","3"],["2","0.25","3"]]"#);
+        let mut appended: Table = Table::try_from(r#"[["amount","id"],["5","3"]]"#).unwrap();
+        appended.set_header(true);
+        appended.write_sqlite(&conn, "t", SqliteMode::Append).unwrap();
+        let table = Table::from_sqlite_query(&conn, "SELECT id, name, amount FROM t WHERE id = '3'").unwrap();
+        assert_eq!(table.to_string(), r#"[["id","name","amount"],["3","","5"]]"#);
+        appended.write_sqlite(&conn, "t", SqliteMode::Replace).unwrap();
+        let table = Table::from_sqlite_query(&conn, "SELECT * FROM t").unwrap();
+        assert_eq!(table.to_string(), r#"[["amount","id"],["5","3"]]"#);
+        assert!(Table::from_sqlite_query(&conn, "SELECT * FROM missing").is_err());
+        let mut money: Table = Table::try_from(r#"[["price"],["USD 1.5"]]"#).unwrap();
+        money.set_header(true);
+        money.write_sqlite(&conn, "m", SqliteMode::Create).unwrap();
+        let price: String = conn.query_row("SELECT price FROM m", [], |row| row.get(0)).unwrap();
+        assert_eq!(price, "USD 1.50");
+        assert!(Table::from_sqlite_query(&conn, "SELECT * FROM m").unwrap().cell(1, 0).unwrap().is_money());
+        // without a header, columns are named by position and every row is written
+        let table: Table = Table::try_from(r#"[["a","1"],["b","2"]]"#).unwrap();
+        table.write_sqlite(&conn, "n", SqliteMode::Create).unwrap();
+        let table = Table::from_sqlite_query(&conn, "SELECT * FROM n").unwrap();
+        assert_eq!(table.to_string(), r#"[["column_1","column_2"],["a","1"],["b","2"]]"#);
+        let err = Table::new().write_sqlite(&conn, "e", SqliteMode::Create).unwrap_err();
+        assert!(err.to_string().contains("without columns"));
+    }
+
+}
@@ -0,0 +1,376 @@
+use chrono::NaiveDate;
+use rust_decimal::Decimal;
+use std::collections::HashMap;
+use std::io::{Error, ErrorKind, Read};
+use std::str::FromStr;
+use super::cell::{Cell, ParseOptions};
+use super::table::Table;
+
+/// The names of the columns of a bank statement.
+const HEADER: [&str; 6] = ["date", "payee", "memo", "amount", "id", "type"];
+
+/// Options used to read a `Table` from a QIF bank statement.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct QifOptions {
+    /// Whether dates have the day before the month, like `31/01/2024`.  Otherwise dates have the
+    /// month before the day, like `01/31/2024`.
+    pub day_first: bool,
+    /// How amounts are parsed.
+    pub parse: ParseOptions,
+}
+
+/// Returns a table with the header of a bank statement.
+fn statement_table() -> Table {
+    Table::with_header(HEADER)
+}
+
+/// Returns an invalid data error.
+fn invalid(message: String) -> Error {
+    Error::new(ErrorKind::InvalidData, message)
+}
+
+/// Returns a text cell, or an empty cell for a missing field.
+fn text(s: String) -> Cell {
+    match s.is_empty() {
+        true => Cell::Empty,
+        false => Cell::Text(s),
+    }
+}
+
+/// Parses an OFX date like `20240131` or `20240131120000.000[-5:EST]`.
+fn ofx_date(s: &str) -> Option<NaiveDate> {
+    let digits = s.get(..8).filter(|d| d.chars().all(|c| c.is_ascii_digit()))?;
+    NaiveDate::from_ymd_opt(digits[..4].parse().ok()?, digits[4..6].parse().ok()?, digits[6..8].parse().ok()?)
+}
+
+/// Parses a QIF date like `01/31/2024`, `1/31'24` or `01-31-24`.
+fn qif_date(s: &str, day_first: bool) -> Option<NaiveDate> {
+    let parts: Vec<&str> = s.trim().split(['/', '\'', '-', '.']).map(str::trim).collect();
+    let [a, b, y] = parts[..] else {
+        return None;
+    };
+    let (month, day) = match day_first {
+        true => (b.parse().ok()?, a.parse().ok()?),
+        false => (a.parse().ok()?, b.parse().ok()?),
+    };
+    let year: i32 = match y.len() {
+        4 => y.parse().ok()?,
+        2 => match y.parse::<i32>().ok()? {
+            y if y < 70 => 2000 + y,
+            y => 1900 + y,
+        },
+        _ => return None,
+    };
+    NaiveDate::from_ymd_opt(year, month, day)
+}
+
+/// The QIF types of sections with bank, cash, credit card, asset, liability and investment
+/// transactions.
+const QIF_TRANSACTION_TYPES: [&str; 6] = ["Bank", "Cash", "CCard", "Oth A", "Oth L", "Invst"];
+
+/// Parses a statement amount, which is a plain signed number like `-12.34` or `(12.34)`, using
+/// the specified options.  Percentages and money are not amounts.
+fn amount(s: &str, options: &ParseOptions) -> Option<Decimal> {
+    match Cell::try_parse(s, options).ok()? {
+        Cell::Number(d) => Some(d),
+        Cell::Formatted(d, format) if !format.percent => Some(d),
+        _ => None,
+    }
+}
+
+/// Decodes the entities of OFX text.
+fn decode(s: &str) -> String {
+    s.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&nbsp;", " ")
+        .replace("&amp;", "&")
+}
+
+/// Returns the values of the elements in an OFX aggregate, by upper case element name.  The
+/// first value of an element is kept.
+fn ofx_elements(aggregate: &str) -> HashMap<String, String> {
+    let mut elements: HashMap<String, String> = HashMap::new();
+    for part in aggregate.split('<').skip(1) {
+        let Some((tag, value)) = part.split_once('>') else {
+            continue;
+        };
+        if tag.starts_with('/') {
+            continue;
+        }
+        let value = value.trim();
+        if !value.is_empty() {
+            elements.entry(tag.trim().to_ascii_uppercase()).or_insert_with(|| decode(value));
+        }
+    }
+    elements
+}
+
+impl Table {
+
+    /// Reads a table from an OFX bank statement.
+    pub fn read_ofx<R: Read>(reader: &mut R) -> std::io::Result<Table> {
+        let mut s = String::new();
+        reader.read_to_string(&mut s)?;
+        Table::from_ofx(&s)
+    }
+
+    /// Parses a table from an OFX bank statement, in either the SGML (OFX 1.x) or XML (OFX 2.x)
+    /// variant.
+    ///
+    /// The table has a row for each transaction, after a header of `date`, `payee`, `memo`,
+    /// `amount`, `id` and `type`.  Dates are date cells like `2024-01-31`, amounts are exact
+    /// numbers, the id is the `FITID` and the type is the `TRNTYPE` of the transaction, like
+    /// `DEBIT`.  A date that cannot be parsed is kept as text.
+    ///
+    /// Amounts use a decimal point or, as some banks do, a decimal comma, without grouping.  Use
+    /// `Table::from_ofx_with` for amounts grouped like `1.000,50`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tablefi::Table;
+    ///
+    /// let ofx = "OFXHEADER:100\nDATA:OFXSGML\n\n<OFX><BANKMSGSRSV1><STMTTRNRS><STMTRS><BANKTRANLIST>
+    /// <STMTTRN><TRNTYPE>DEBIT<DTPOSTED>20240131120000[-5:EST]<TRNAMT>-12.34<FITID>A1<NAME>Coffee &amp; Co
+    /// </STMTTRN></BANKTRANLIST></STMTRS></STMTTRNRS></BANKMSGSRSV1></OFX>";
+    /// let table = Table::from_ofx(ofx).unwrap();
+    /// assert_eq!(table.to_string(), r#"[["date","payee","memo","amount","id","type"],["2024-01-31","Coffee & Co","","-12.34","A1","DEBIT"]]"#);
+    /// ```
+    pub fn from_ofx(value: &str) -> Result<Table, std::io::Error> {
+        Table::ofx_table(value, |amount| {
+            let amount = amount.replace('+', "");
+            // some banks use a comma as the decimal separator
+            let amount = match amount.contains('.') {
+                true => amount,
+                false => amount.replace(',', "."),
+            };
+            Decimal::from_str(&amount).ok()
+        })
+    }
+
+    /// Parses a table from an OFX bank statement, using the specified options for amounts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_decimal::Decimal;
+    /// use tablefi::{Cell, ParseOptions, Table};
+    ///
+    /// let ofx = "<OFX><STMTTRN><TRNTYPE>CREDIT<DTPOSTED>20240131<TRNAMT>1.000,50</STMTTRN></OFX>";
+    /// let table = Table::from_ofx_with(ofx, &ParseOptions::german()).unwrap();
+    /// assert_eq!(table.cell(1, 3), Some(Cell::Number(Decimal::new(100050, 2))));
+    /// ```
+    pub fn from_ofx_with(value: &str, options: &ParseOptions) -> Result<Table, std::io::Error> {
+        Table::ofx_table(value, |s| amount(s, options))
+    }
+
+    /// Parses a table from an OFX bank statement, parsing amounts with `amount`.
+    fn ofx_table(value: &str, amount: impl Fn(&str) -> Option<Decimal>) -> Result<Table, std::io::Error> {
+        let upper = value.to_ascii_uppercase();
+        if !upper.contains("<OFX>") {
+            return Err(invalid("OFX element not found".to_string()));
+        }
+        let mut table = statement_table();
+        let mut rest = 0;
+        while let Some(start) = upper[rest..].find("<STMTTRN>").map(|i| rest + i) {
+            let end = upper[start..].find("</STMTTRN>").map(|i| start + i)
+                .ok_or_else(|| invalid(format!("Unterminated STMTTRN in ofx transaction {}", table.rows())))?;
+            let elements = ofx_elements(&value[start..end]);
+            let field = |name: &str| elements.get(name).cloned().unwrap_or_default();
+            let amount = amount(&field("TRNAMT"))
+                .ok_or_else(|| invalid(format!("Invalid amount {:?} in ofx transaction {}", field("TRNAMT"), table.rows())))?;
+            let date = field("DTPOSTED");
+            table.push_row(vec![
+                ofx_date(&date).map(Cell::Date).unwrap_or_else(|| text(date)),
+                text(field("NAME")),
+                text(field("MEMO")),
+                Cell::Number(amount),
+                text(field("FITID")),
+                text(field("TRNTYPE")),
+            ]);
+            rest = end;
+        }
+        Ok(table)
+    }
+
+    /// Reads a table from a QIF bank statement.
+    pub fn read_qif<R: Read>(reader: &mut R) -> std::io::Result<Table> {
+        let mut s = String::new();
+        reader.read_to_string(&mut s)?;
+        Table::from_qif(&s)
+    }
+
+    /// Parses a table from a QIF bank statement.
+    ///
+    /// The table has a row for each transaction, after a header of `date`, `payee`, `memo`,
+    /// `amount`, `id` and `type`.  Dates are date cells like `2024-01-31` and amounts are
+    /// exact numbers.  A date that cannot be parsed is kept as text.  The id is the check or
+    /// reference number (`N`) and, since QIF has no transaction type, the type is `CREDIT` or
+    /// `DEBIT` by the sign of the amount.
+    ///
+    /// Only the transactions in sections like `!Type:Bank`, `!Type:CCard` or `!Type:Invst` are
+    /// read.  Other sections, like `!Account` blocks or `!Type:Cat` lists of categories, are
+    /// skipped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tablefi::Table;
+    ///
+    /// let qif = "!Type:Bank\nD01/31/2024\nT-1,234.50\nPRent\nMJanuary\nN101\n^\n";
+    /// let table = Table::from_qif(qif).unwrap();
+    /// assert_eq!(table.to_string(), r#"[["date","payee","memo","amount","id","type"],["2024-01-31","Rent","January","-1234.50","101","DEBIT"]]"#);
+    /// ```
+    pub fn from_qif(value: &str) -> Result<Table, std::io::Error> {
+        Table::from_qif_with(value, &QifOptions::default())
+    }
+
+    /// Parses a table from a QIF bank statement using the specified options.
+    pub fn from_qif_with(value: &str, options: &QifOptions) -> Result<Table, std::io::Error> {
+        let mut table = statement_table();
+        let mut fields: HashMap<char, String> = HashMap::new();
+        let mut transactions = false;
+        for line in value.lines().map(str::trim) {
+            let mut chars = line.chars();
+            match chars.next() {
+                // headers like !Type:Bank or !Account start a section, while options like
+                // !Option:AutoSwitch do not
+                Some('!') => {
+                    let header = chars.as_str().to_ascii_lowercase();
+                    if !header.starts_with("option:") && !header.starts_with("clear:") {
+                        transactions = header.strip_prefix("type:")
+                            .is_some_and(|kind| QIF_TRANSACTION_TYPES.iter().any(|t| t.eq_ignore_ascii_case(kind.trim())));
+                        fields.clear();
+                    }
+                }
+                Some(_) if !transactions => {}
+                Some('^') if !fields.is_empty() => {
+                    let field = |code: char| fields.get(&code).cloned().unwrap_or_default();
+                    let date = field('D');
+                    let amount = match fields.get(&'T').or(fields.get(&'U')) {
+                        Some(s) => amount(s, &options.parse)
+                            .ok_or_else(|| invalid(format!("Invalid amount {:?} in qif transaction {}", s, table.rows())))?,
+                        None => Decimal::ZERO,
+                    };
+                    let kind = match amount.is_sign_negative() && !amount.is_zero() {
+                        true => "DEBIT",
+                        false => "CREDIT",
+                    };
+                    table.push_row(vec![
+                        qif_date(&date, options.day_first).map(Cell::Date).unwrap_or_else(|| text(date)),
+                        text(field('P')),
+                        text(field('M')),
+                        Cell::Number(amount),
+                        text(field('N')),
+                        Cell::Text(kind.to_string()),
+                    ]);
+                    fields.clear();
+                }
+                Some('^') | None => {}
+                Some(code) => {
+                    // the first value of a field is kept, so splits do not replace the transaction
+                    fields.entry(code).or_insert_with(|| chars.as_str().trim().to_string());
+                }
+            }
+        }
+        Ok(table)
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dates() {
+        let date = |y, m, d| NaiveDate::from_ymd_opt(y, m, d);
+        assert_eq!(ofx_date("20240131"), date(2024, 1, 31));
+        assert_eq!(ofx_date("20240131120000.000[-5:EST]"), date(2024, 1, 31));
+        assert_eq!(ofx_date("2024013"), None);
+        assert_eq!(ofx_date("20241331"), None);
+        assert_eq!(ofx_date("20240230"), None);
+        assert_eq!(qif_date("01/31/2024", false), date(2024, 1, 31));
+        assert_eq!(qif_date("1/31'24", false), date(2024, 1, 31));
+        assert_eq!(qif_date(" 1/ 5/98", false), date(1998, 1, 5));
+        assert_eq!(qif_date("31.01.2024", true), date(2024, 1, 31));
+        assert_eq!(qif_date("31/01/2024", false), None);
+        assert_eq!(qif_date("2024", false), None);
+    }
+
+    #[test]
+    fn test_from_ofx() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<?OFX OFXHEADER="200" VERSION="220"?>
+<OFX>
+  <CREDITCARDMSGSRSV1><CCSTMTTRNRS><CCSTMTRS><BANKTRANLIST>
+    <STMTTRN>
+      <TRNTYPE>CREDIT</TRNTYPE>
+      <DTPOSTED>20240201</DTPOSTED>
+      <TRNAMT>+1000,50</TRNAMT>
+      <FITID>B2</FITID>
+      <PAYEE><NAME>Employer &lt;Payroll&gt;</NAME></PAYEE>
+      <MEMO>Salary</MEMO>
+    </STMTTRN>
+    <STMTTRN>
+      <TRNTYPE>DEBIT</TRNTYPE>
+      <DTPOSTED>20240202</DTPOSTED>
+      <TRNAMT>-0.10</TRNAMT>
+      <FITID>B3</FITID>
+    </STMTTRN>
+  </BANKTRANLIST></CCSTMTRS></CCSTMTTRNRS></CREDITCARDMSGSRSV1>
+</OFX>"#;
+        let table = Table::from_ofx(xml).unwrap();
+        assert_eq!(table.rows(), 3);
+        assert_eq!(table.row(1).unwrap().to_string(), r#"["2024-02-01","Employer <Payroll>","Salary","1000.50","B2","CREDIT"]"#);
+        assert_eq!(table.row(2).unwrap().to_string(), r#"["2024-02-02","","","-0.10","B3","DEBIT"]"#);
+        assert_eq!(table.cell(2, 3), Some(Cell::Number(Decimal::new(-10, 2))));
+        assert_eq!(table.cell(2, 0), Some(Cell::Date(NaiveDate::from_ymd_opt(2024, 2, 2).unwrap())));
+        assert_eq!(table.cell(2, 1), Some(Cell::Empty));
+        assert!(Table::from_ofx("a,b\n").is_err());
+        assert!(Table::from_ofx("<OFX><STMTTRN><TRNAMT>abc</STMTTRN></OFX>").is_err());
+        assert!(Table::from_ofx("<OFX><STMTTRN><TRNAMT>1").is_err());
+        assert_eq!(Table::read_ofx(&mut "<ofx></ofx>".as_bytes()).unwrap().rows(), 1);
+        // a date that cannot be parsed is kept as text, like qif
+        let table = Table::from_ofx("<OFX><STMTTRN><DTPOSTED>garbage<TRNAMT>1</STMTTRN></OFX>").unwrap();
+        assert_eq!(table.cell(1, 0), Some(Cell::Text("garbage".to_string())));
+        assert_eq!(table.cell(1, 3), Some(Cell::Number(Decimal::ONE)));
+        // grouped amounts need the parse options
+        let ofx = "<OFX><STMTTRN><TRNAMT>-1.000,50</STMTTRN></OFX>";
+        assert!(Table::from_ofx(ofx).is_err());
+        let table = Table::from_ofx_with(ofx, &ParseOptions::german()).unwrap();
+        assert_eq!(table.cell(1, 3), Some(Cell::Number(Decimal::new(-100050, 2))));
+        assert!(Table::from_ofx_with("<OFX><STMTTRN><TRNAMT>abc</STMTTRN></OFX>", &ParseOptions::default()).is_err());
+        // percentages and money are not amounts
+        let err = Table::from_ofx_with("<OFX><STMTTRN><TRNAMT>12%</STMTTRN></OFX>", &ParseOptions::default()).unwrap_err();
+        assert!(err.to_string().contains("Invalid amount"));
+        assert!(Table::from_ofx_with("<OFX><STMTTRN><TRNAMT>USD 5</STMTTRN></OFX>", &ParseOptions::default()).is_err());
+        assert_eq!(amount("(12.34)", &ParseOptions::default()), Some(Decimal::new(-1234, 2)));
+    }
+
+    #[test]
+    fn test_from_qif() {
+        let qif = "!Type:Bank\nD1/31'24\nT500.00\nPDeposit\n^\nD02/01/2024\nU-25.5\nT-25.50\nPShop\nN\nSGroceries\n$-20.00\nSHome\n$-5.50\n^\n";
+        let table = Table::from_qif(qif).unwrap();
+        assert_eq!(table.to_string(), r#"[["date","payee","memo","amount","id","type"],["2024-01-31","Deposit","","500.00","","CREDIT"],["2024-02-01","Shop","","-25.50","","DEBIT"]]"#);
+        let options = QifOptions { day_first: true, parse: ParseOptions::german() };
+        let table = Table::from_qif_with("!Type:Bank\nD31.01.2024\nT-1.234,50\n^\n", &options).unwrap();
+        assert_eq!(table.row(1).unwrap().to_string(), r#"["2024-01-31","","","-1234.50","","DEBIT"]"#);
+        assert!(Table::from_qif("!Type:Bank\nTabc\n^\n").is_err());
+        let table = Table::from_qif("!Type:Bank\nDsoon\nT1\n^\n").unwrap();
+        assert_eq!(table.cell(1, 0), Some(Cell::Text("soon".to_string())));
+        assert_eq!(Table::read_qif(&mut "!Type:Bank\n".as_bytes()).unwrap().rows(), 1);
+        // percentages and money are not amounts
+        assert!(Table::from_qif("!Type:Bank\nT12%\n^\n").is_err());
+        assert!(Table::from_qif("!Type:Bank\nTUSD 5\n^\n").is_err());
+        // only transaction sections are read
+        let qif = "!Option:AutoSwitch\n!Account\nNChecking\nTBank\n^\nNSavings\nTBank\n^\n!Clear:AutoSwitch\n\
+            !Type:Cat\nNGroceries\nDFood and household\nE\n^\n!Type:Class\nNWork\n^\n\
+            !Account\nNChecking\nTBank\n^\n!Type:Bank\nD01/31/2024\nT-12.50\nPShop\n^\n!Type:Memorized\nT-9.99\nPStream\n^\n";
+        let table = Table::from_qif(qif).unwrap();
+        assert_eq!(table.to_string(), r#"[["date","payee","memo","amount","id","type"],["2024-01-31","Shop","","-12.50","","DEBIT"]]"#);
+    }
+
+}
@@ -1,9 +1,16 @@
 use grid::{Grid, Order};
+use rust_decimal::Decimal;
 use serde::{ser::SerializeSeq, Deserialize, Deserializer, Serialize, Serializer};
-use std::io::Write;
-use std::usize;
+use serde_json::value::RawValue;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use std::fmt;
+use std::io::{Error, ErrorKind, Read, Write};
 pub use super::Cell;
 pub use super::Slice;
+use super::cell::{cell_from_field, cell_from_raw, CellError, Inference, ParseOptions};
+use super::csv::CsvOptions;
+use super::json::{JsonCell, JsonObject, JsonOptions, JsonRecord, JsonRecordsOptions, JsonStyle};
 
 /// Represents a 2-dimensional table structure holding `Cell` data.
 /// 
@@ -39,6 +46,7 @@ pub use super::Slice;
 #[derive(Clone, Debug, Default)]
 pub struct Table {
     grid: Grid<Cell>,
+    header: bool,
 }
 
 impl Serialize for Table {
@@ -62,12 +70,13 @@ impl<'de> Deserialize<'de> for Table {
     where
         D: Deserializer<'de>,
     {
+        // a json array of arrays does not record a header, see Table::from_json_with
         let rows_of_cells: Vec<Vec<Cell>> = Vec::<Vec<Cell>>::deserialize(deserializer)?;
         let mut grid = Grid::new_with_order(0, 0, Order::RowMajor);
         for row_vec in rows_of_cells {
             grid.push_row(row_vec);
         }
-        Ok(Table { grid: grid })
+        Ok(Table { grid, header: false })
     }
 
 }
@@ -76,27 +85,224 @@ impl TryFrom<&str> for Table {
     type Error = serde_json::Error;
     
     fn try_from(value: &str) -> Result<Self, Self::Error> {
-        serde_json::from_str(value)
+        Table::from_json_with(value, &JsonOptions::default())
     }
 
 }
 
-impl ToString for Table {
+impl fmt::Display for Table {
 
-    fn to_string(&self) -> String {
-        serde_json::to_string(self).unwrap()
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", serde_json::to_string(self).map_err(|_| fmt::Error)?)
     }
 
 }
 
+/// The rank of the type of a cell when sorting.
+fn sort_rank(cell: &Cell) -> u8 {
+    match cell {
+        Cell::Number(_) | Cell::Formatted(_, _) => 0,
+        Cell::Money(_, _) => 1,
+        Cell::Date(_) | Cell::DateTime(_) => 2,
+        Cell::Bool(_) => 3,
+        Cell::Text(_) => 4,
+        Cell::Error(_) => 5,
+        Cell::Empty => 6,
+    }
+}
+
+/// The order of two cells when sorting, which is total so that every cell can be sorted.
+fn sort_order(cell1: &Cell, cell2: &Cell) -> Ordering {
+    let by_currency = cell1.currency().map(|c| c.code()).cmp(&cell2.currency().map(|c| c.code()));
+    sort_rank(cell1).cmp(&sort_rank(cell2))
+        .then(by_currency)
+        .then_with(|| cell1.compare_value(cell2).unwrap_or(Ordering::Equal))
+}
+
 impl Table {
 
     pub fn new() -> Self {
         Table {
-            grid: Grid::new_with_order(0, 0, Order::RowMajor)
+            grid: Grid::new_with_order(0, 0, Order::RowMajor),
+            header: false,
         }
     }
 
+    /// Creates a table with a header of column names as the first row.
+    ///
+    /// The header is kept as text, and is excluded from `Table::find_value`, arithmetic, sorting
+    /// and sums.  Rows are still indexed from the header, so the first row below the header is
+    /// row `1`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use rust_decimal::Decimal;
+    /// use tablefi::{Cell, Table};
+    ///
+    /// let mut table = Table::with_header(["account", "2024"]);
+    /// table.push_row(vec![Cell::from("cash"), Cell::from("20")]);
+    /// table.push_row(vec![Cell::from("loan"), Cell::from("-5")]);
+    /// table.checked_add_value(Decimal::ONE).unwrap();
+    /// assert_eq!(table.to_string(), r#"[["account","2024"],["cash","21"],["loan","-4"]]"#);
+    /// assert_eq!(table.col_by_name("2024").unwrap().to_string(), r#"["21","-4"]"#);
+    /// assert_eq!(table.sum_col(1), Some(Cell::from("17")));
+    ///
+    /// table.sort_by_col(1, true);
+    /// table.rename_col("2024", "balance");
+    /// assert_eq!(table.to_string(), r#"[["account","balance"],["loan","-4"],["cash","21"]]"#);
+    /// ```
+    pub fn with_header<I: IntoIterator<Item = S>, S: AsRef<str>>(names: I) -> Table {
+        let names: Vec<Cell> = names.into_iter().map(|name| Cell::Text(name.as_ref().to_string())).collect();
+        let mut table = Table::new();
+        if !names.is_empty() {
+            table.push_row(names);
+            table.header = true;
+        }
+        table
+    }
+
+    /// Whether the first row of the table is a header of column names.
+    pub fn has_header(&self) -> bool {
+        self.header
+    }
+
+    /// Sets whether the first row of the table is a header of column names.
+    pub fn set_header(&mut self, header: bool) {
+        self.header = header;
+    }
+
+    /// Returns the header of column names, if the table has a header.
+    pub fn header(&self) -> Option<Slice> {
+        self.row(0).filter(|_| self.header)
+    }
+
+    /// The index of the first row below the header.
+    pub(crate) fn body_start(&self) -> usize {
+        usize::from(self.header)
+    }
+
+    /// Returns the index of the first column with the name, if the table has a header.
+    pub fn col_index(&self, name: &str) -> Option<usize> {
+        self.header()?.iter().position(|cell| cell.to_string() == name)
+    }
+
+    /// Returns the cells below the header of the first column with the name.
+    pub fn col_by_name(&self, name: &str) -> Option<Slice> {
+        let col = self.col_index(name)?;
+        Some(Slice::from_iter(self.grid.iter_col(col).skip(self.body_start()).cloned()))
+    }
+
+    /// Renames the first column with the name, returning whether the column was found.
+    pub fn rename_col(&mut self, name: &str, new_name: &str) -> bool {
+        match self.col_index(name).and_then(|col| self.grid.get_mut(0, col)) {
+            Some(cell) => {
+                *cell = Cell::Text(new_name.to_string());
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Parses a table from a json array of arrays using the specified options.
+    ///
+    /// A json array of arrays does not record whether the table has a header, so the header is
+    /// not kept by `to_string` and `Table::try_from`.  Set `JsonOptions::header` to read the first
+    /// row back as the header.  Column inference overrides in the options apply to the columns of
+    /// the table.
+    pub fn from_json_with(value: &str, options: &JsonOptions) -> Result<Table, serde_json::Error> {
+        let rows_of_values: Vec<Vec<&RawValue>> = serde_json::from_str(value)?;
+        // the header is kept as text
+        let header_options = ParseOptions {
+            inference: Inference::AlwaysText,
+            column_inference: HashMap::new(),
+            ..options.parse.clone()
+        };
+        let mut table = Table::new();
+        for (r, row_vec) in rows_of_values.into_iter().enumerate() {
+            let header = r == 0 && options.header;
+            let parse = match header {
+                true => &header_options,
+                false => &options.parse,
+            };
+            let row = row_vec.into_iter()
+                .enumerate()
+                .map(|(c, val)| cell_from_raw(val, parse, c))
+                .collect::<Result<Vec<Cell>, String>>()
+                .map_err(serde::de::Error::custom)?;
+            match header {
+                true => table = Table::with_header(row.iter().map(Cell::to_string)),
+                false => table.push_row(row),
+            }
+        }
+        Ok(table)
+    }
+
+    /// Parses a table from json records, an array of objects like `[{"name":"a","amount":"1.00"}]`.
+    ///
+    /// The first row of the table is the header of keys, in the order they appear in the records,
+    /// so the table has a header.
+    /// Keys missing from a record are empty cells.
+    pub fn from_json_records(value: &str, options: &JsonRecordsOptions) -> Result<Table, serde_json::Error> {
+        let records: Vec<JsonObject> = serde_json::from_str(value)?;
+        let mut table = Table::new();
+        for row in options.rows(records).map_err(serde::de::Error::custom)? {
+            table.push_row(row);
+        }
+        table.header = true;
+        Ok(table)
+    }
+
+    /// Writes the table as json records, using the header as the keys.
+    ///
+    /// When the header has duplicate names, the value of the last column with that name is kept.
+    /// A table without a header has its keys named by position, like `column_1`, and every row is
+    /// written.
+    pub fn write_json_records<W: Write>(&self, writer: &mut W) -> serde_json::Result<()> {
+        self.write_json_records_with(writer, JsonStyle::Text)
+    }
+
+    /// Writes the table as json records in the specified style, using the header as the keys.
+    pub fn write_json_records_with<W: Write>(&self, writer: &mut W, style: JsonStyle) -> serde_json::Result<()> {
+        let header: Vec<String> = match self.header() {
+            Some(header) => header.iter().map(|cell| cell.to_string()).collect(),
+            None => (1..=self.cols()).map(|c| format!("column_{}", c)).collect(),
+        };
+        let keys = JsonRecord::keys(&header);
+        let records: Vec<JsonRecord> = self.grid.iter_rows()
+            .skip(self.body_start())
+            .map(|row_iter| JsonRecord { keys: &keys, cells: row_iter.collect(), style })
+            .collect();
+        serde_json::to_writer(writer, &records)
+    }
+
+    /// Formats the table as json records, using the header as the keys.
+    pub fn to_json_records(&self) -> Result<String, serde_json::Error> {
+        self.to_json_records_with(JsonStyle::Text)
+    }
+
+    /// Formats the table as json records in the specified style, using the header as the keys.
+    pub fn to_json_records_with(&self, style: JsonStyle) -> Result<String, serde_json::Error> {
+        let mut writer: Vec<u8> = Vec::new();
+        self.write_json_records_with(&mut writer, style)?;
+        Ok(String::from_utf8(writer).unwrap())
+    }
+
+    /// Writes the table as a json array of arrays in the specified style.
+    pub fn write_json_with<W: Write>(&self, writer: &mut W, style: JsonStyle) -> serde_json::Result<()> {
+        let rows: Vec<Vec<JsonCell>> = self.grid.iter_rows()
+            .map(|row_iter| row_iter.map(|cell| JsonCell(cell, style)).collect())
+            .collect();
+        serde_json::to_writer(writer, &rows)
+    }
+
+    /// Formats the table as a json array of arrays in the specified style.
+    pub fn to_json_with(&self, style: JsonStyle) -> Result<String, serde_json::Error> {
+        let mut writer: Vec<u8> = Vec::new();
+        self.write_json_with(&mut writer, style)?;
+        Ok(String::from_utf8(writer).unwrap())
+    }
+
     /// Provides a reference to the internal grid.
     fn grid(&self) -> &Grid<Cell> {
         &self.grid
@@ -135,10 +341,7 @@ impl Table {
 
     /// Removes a column from the table at the specified index.
     pub fn remove_col(&mut self, idx: usize) -> Option<Slice> {
-        match self.grid.remove_col(idx) {
-            Some(cells) => Some(Slice::from(cells)),
-            None => None,
-        }
+        self.grid.remove_col(idx).map(Slice::from)
     }
 
     /// Replaces a column at the specified index with a new column.
@@ -160,8 +363,14 @@ impl Table {
     }
 
     /// Inserts a new row at the specified index.
+    ///
+    /// Inserting a row above the header clears the header, since the header is no longer the
+    /// first row.  Use `Table::replace_row` to replace the header.
     pub fn insert_row<C: Into<Vec<Cell>>>(&mut self, idx: usize, new_row: C) {
         self.grid.insert_row(idx, new_row.into());
+        if idx == 0 {
+            self.header = false;
+        }
     }
 
     /// Appends a new row to the table.
@@ -170,28 +379,113 @@ impl Table {
     }
 
     /// Removes a row from the table at the specified index.
+    ///
+    /// Removing the header clears the header, so the next row is not taken as the header.
     pub fn remove_row(&mut self, idx: usize) -> Option<Slice> {
-        match self.grid.remove_row(idx) {
-            Some(cells) => Some(Slice::from(cells)),
-            None => None,
+        let old_row = self.grid.remove_row(idx).map(Slice::from);
+        if idx == 0 && old_row.is_some() {
+            self.header = false;
         }
+        old_row
     }
 
     /// Replaces a row at the specified index with a new row.
+    ///
+    /// Replacing the header keeps the header, with the new row as the names of the columns.
     pub fn replace_row<C: Into<Vec<Cell>>>(&mut self, idx: usize, new_row: C) -> Option<Slice> {
+        let header = self.header;
         let old_row = self.remove_row(idx);
         self.insert_row(idx, new_row);
+        self.header = header;
         old_row
     }
 
+    /// Applies a checked operation to every cell, leaving the table unchanged when an error is
+    /// returned.
+    fn checked_apply(&mut self, value: Decimal, op: fn(&mut Cell, Decimal) -> Result<(), CellError>) -> Result<&mut Self, CellError> {
+        let mut grid = self.grid.clone();
+        for cell in grid.iter_mut().skip(self.body_start() * self.cols()) {
+            op(cell, value)?;
+        }
+        self.grid = grid;
+        Ok(self)
+    }
+
+    /// Adds value to all numerical cells below the header, like `Cell::checked_add_value`.  The table
+    /// is unchanged when an error is returned.
+    ///
+    /// # Examples
+    /// ```
+    /// use rust_decimal::Decimal;
+    /// use tablefi::{CellError, Table};
+    ///
+    /// let mut table: Table = Table::try_from(r#"[["a","b"],["1","79228162514264337593543950335"]]"#).unwrap();
+    /// assert_eq!(table.checked_add_value(Decimal::ONE).unwrap_err(), CellError::Overflow);
+    /// assert_eq!(table.to_string(), r#"[["a","b"],["1","79228162514264337593543950335"]]"#);
+    /// ```
+    pub fn checked_add_value(&mut self, value: Decimal) -> Result<&mut Self, CellError> {
+        self.checked_apply(value, Cell::checked_add_value)
+    }
+
+    /// Subtracts value from all numerical cells below the header, like `Cell::checked_sub_value`.  The
+    /// table is unchanged when an error is returned.
+    pub fn checked_sub_value(&mut self, value: Decimal) -> Result<&mut Self, CellError> {
+        self.checked_apply(value, Cell::checked_sub_value)
+    }
+
+    /// Multiplies value to all numerical cells below the header, like `Cell::checked_mul_value`.  The
+    /// table is unchanged when an error is returned.
+    pub fn checked_mul_value(&mut self, value: Decimal) -> Result<&mut Self, CellError> {
+        self.checked_apply(value, Cell::checked_mul_value)
+    }
+
+    /// Divides value from all numerical cells below the header, like `Cell::checked_div_value`.  The
+    /// table is unchanged when an error is returned.
+    pub fn checked_div_value(&mut self, value: Decimal) -> Result<&mut Self, CellError> {
+        self.checked_apply(value, Cell::checked_div_value)
+    }
+
+    /// Sums the numbers and money in a column below the header, ignoring other cells.
+    ///
+    /// An error in the column, money in different currencies or with numbers, or a sum too large
+    /// for a `Decimal` gives `Cell::Error`.  A column without numbers sums to `0`.
+    pub fn sum_col(&self, col: usize) -> Option<Cell> {
+        if col >= self.cols() { return None; }
+        let sum = self.grid.iter_col(col)
+            .skip(self.body_start())
+            .filter(|cell| cell.to_decimal().is_some() || cell.is_error())
+            .fold(None, |sum: Option<Cell>, cell| Some(match sum {
+                Some(sum) => &sum + cell,
+                None => cell.clone(),
+            }));
+        Some(sum.unwrap_or(Cell::Number(Decimal::ZERO)))
+    }
+
+    /// Sorts the rows below the header by the values in a column.
+    ///
+    /// The sort is stable.  Numbers, money, dates, booleans and text are each ordered by value,
+    /// and are sorted in that order, followed by errors and empty cells.  Money is grouped by
+    /// currency.  Descending order reverses this.
+    pub fn sort_by_col(&mut self, col: usize, ascending: bool) {
+        let start = self.body_start();
+        if col >= self.cols() || self.rows() <= start + 1 { return; }
+        let cols = self.cols();
+        let mut rows: Vec<Vec<Cell>> = self.grid.iter_rows().map(|row| row.cloned().collect()).collect();
+        rows[start..].sort_by(|r1, r2| match ascending {
+            true => sort_order(&r1[col], &r2[col]),
+            false => sort_order(&r2[col], &r1[col]),
+        });
+        self.grid = Grid::from_vec_with_order(rows.concat(), cols, Order::RowMajor);
+    }
+
     /// Returns an array of row and columnn tuples where the value exists in the `Table`.
     /// 
     /// Max limits the search of the values found.
     fn _find_value<T: ?Sized>(&self, other_value: &T, max: Option<usize>) -> Vec<(usize, usize)> where for<'r> &'r T: Into<Cell> {
         let mut vec: Vec<(usize, usize)> = Vec::new();
-        for (r, row_iter) in self.grid.iter_rows().enumerate() {
+        for (r, row_iter) in self.grid.iter_rows().enumerate().skip(self.body_start()) {
             for (c, cell) in row_iter.enumerate() {
-                if cell.equal_value(&other_value) {
+                if cell.equal_value(other_value) {
                     vec.push((r, c));
                     if vec.len() == max.unwrap_or(usize::MAX) {
                         return vec;
@@ -202,58 +496,121 @@ impl Table {
         vec
     }
 
-    /// Returns an array of row and columnn tuples where the value exists in the `Table`.
+    /// Returns an array of row and columnn tuples where the value exists in the `Table`, below the
+    /// header.
     pub fn find_value<T: ?Sized>(&self, other_value: &T) -> Vec<(usize, usize)> where for<'r> &'r T: Into<Cell> {
         self._find_value(other_value, None)
     }
 
-    /// Whether the value exists in the `Table`.
+    /// Whether the value exists in the `Table`, below the header.
     pub fn contains_value<T: ?Sized>(&self, other_value: &T) -> bool where for<'r> &'r T: Into<Cell> {
-        self._find_value(other_value, Some(1)).len() > 0
+        !self._find_value(other_value, Some(1)).is_empty()
     }
 
     /// Writes the table as csv.
+    ///
+    /// Errors are written as their Excel error codes, like `#DIV/0!`, so spreadsheets read them
+    /// back as errors.
     pub fn write_csv<W: Write>(&self, writer: &mut W) -> std::io::Result<()> {
-        for row_iter in self.grid.iter_rows() {
-            let mut first_cell = true;
-            for cell in row_iter {
-                match first_cell {
-                    true => first_cell = false,
-                    false => writer.write_all(b",")?,
-                }
-                let s = cell.to_string();
-                // per RFC 4180, double quotes require two consecutive double quotes ("")
-                let quotes = s.contains(r#"""#);
-                // per RFC 4180, commas, double quotes, and line breaks require the field to be enclosed by double quote
-                let escaping = s.contains(',')
-                    || s.contains('\n')
-                    || s.contains('\r');
-                if quotes || escaping { writer.write_all(b"\"")?; }
-                if quotes {
-                    writer.write_all(s.replace(r#"""#, r#""""#).as_bytes())?;
-                } else {
-                    writer.write_all(s.as_bytes())?;
-                }
-                if quotes || escaping { writer.write_all(b"\"")?; }
+        self.write_csv_with(writer, &CsvOptions::default())
+    }
+
+    /// Writes the table as csv using the specified dialect.
+    ///
+    /// Fails with `ErrorKind::InvalidInput` if the dialect is not valid, see `CsvOptions::validate`,
+    /// or a field cannot be written without quoting.
+    pub fn write_csv_with<W: Write>(&self, writer: &mut W, options: &CsvOptions) -> std::io::Result<()> {
+        options.validate()?;
+        let mut delimiter = [0u8; 4];
+        let delimiter = options.delimiter.encode_utf8(&mut delimiter).as_bytes();
+        for (r, row_iter) in self.grid.iter_rows().enumerate() {
+            if r > 0 { writer.write_all(options.terminator.as_str().as_bytes())?; }
+            for (c, cell) in row_iter.enumerate() {
+                if c > 0 { writer.write_all(delimiter)?; }
+                options.write_field(writer, &cell.to_string())?;
             }
-            writer.write_all(b"\n")?
+        }
+        if options.trailing_newline && self.rows() > 0 {
+            writer.write_all(options.terminator.as_str().as_bytes())?;
         }
         Ok(())
     }
 
     /// Formats the table as csv.
     pub fn to_csv(&self) -> Result<String, std::io::Error> {
+        self.to_csv_with(&CsvOptions::default())
+    }
+
+    /// Formats the table as csv using the specified dialect.
+    pub fn to_csv_with(&self, options: &CsvOptions) -> Result<String, std::io::Error> {
         let mut writer: Vec<u8> = Vec::new();
-        self.write_csv(&mut writer)?;
+        self.write_csv_with(&mut writer, options)?;
         Ok(String::from_utf8(writer).unwrap())
     }
 
+    /// Reads a table from csv.
+    ///
+    /// Fields are parsed per RFC 4180, including quoted fields with doubled quotes, embedded line
+    /// breaks and `\r\n` line endings.  Rows shorter than the longest row are padded with empty
+    /// cells.
+    ///
+    /// Fields are parsed into cells as described by `Cell::parse`, so empty fields become empty
+    /// cells, `true` and `false` become booleans, ISO 8601 dates become dates and Excel error
+    /// codes like `#DIV/0!` become errors.  These are written back out the same way.
+    ///
+    /// Csv does not record the type of a cell, so a round trip is lossy for text that looks like
+    /// another type.  Text like `00123`, `true` or `#N/A` is read back as a number, a boolean or
+    /// an error.  Use `Inference::AlwaysText` for these columns in `CsvOptions::parse` to keep them
+    /// as text.
+    pub fn read_csv<R: Read>(reader: &mut R) -> std::io::Result<Table> {
+        Table::read_csv_with(reader, &CsvOptions::default())
+    }
+
+    /// Reads a table from csv using the specified dialect.
+    pub fn read_csv_with<R: Read>(reader: &mut R, options: &CsvOptions) -> std::io::Result<Table> {
+        let mut s = String::new();
+        reader.read_to_string(&mut s)?;
+        Table::from_csv_with(&s, options)
+    }
+
+    /// Parses a table from a csv string.
+    pub fn from_csv(value: &str) -> Result<Table, std::io::Error> {
+        Table::from_csv_with(value, &CsvOptions::default())
+    }
+
+    /// Parses a table from a csv string using the specified dialect.
+    ///
+    /// Fails with `ErrorKind::InvalidInput` if the dialect is not valid, see `CsvOptions::validate`.
+    pub fn from_csv_with(value: &str, options: &CsvOptions) -> Result<Table, std::io::Error> {
+        let records = options.parse(value)?;
+        let cols = records.iter().map(Vec::len).max().unwrap_or(0);
+        let mut table = Table::new();
+        for (r, mut record) in records.into_iter().enumerate() {
+            if r == 0 && options.header {
+                record.resize(cols, String::new());
+                table = Table::with_header(record);
+                continue;
+            }
+            let mut row = record.iter()
+                .enumerate()
+                .map(|(c, s)| cell_from_field(s, &options.parse, c))
+                .collect::<Result<Vec<Cell>, String>>()
+                .map_err(|e| Error::new(ErrorKind::InvalidData, e))?;
+            row.resize(cols, Cell::default());
+            table.push_row(row);
+        }
+        Ok(table)
+    }
+
 }
 
 #[cfg(test)]
+#[allow(clippy::bool_assert_comparison)]
 mod tests {
     use rust_decimal::Decimal;
     use super::*;
+    use super::super::cell::Inference;
+    use super::super::csv::{CsvEscape, CsvQuoting, CsvTerminator};
 
     #[test]
     fn test_table() {
@@ -270,6 +627,67 @@ mod tests {
         assert_eq!(table.to_string(), r#"[["a","b","c"],["1","2","3"]]"#);
     }
 
+    #[test]
+    fn test_json_with() {
+        let table = Table::from_json_with(r#"[["a","b"],["1.234,5",2.5]]"#, &JsonOptions { parse: ParseOptions::german(), ..Default::default() }).unwrap();
+        assert_eq!(table.cell(1, 0).unwrap().to_decimal(), Some(Decimal::new(12345, 1)));
+        assert_eq!(table.cell(1, 1).unwrap().to_decimal(), Some(Decimal::new(25, 1)));
+        assert!(Table::from_json_with(r#"["a"]"#, &JsonOptions::default()).is_err());
+        let strict = JsonOptions { parse: ParseOptions { strict: true, ..Default::default() }, ..Default::default() };
+        assert!(Table::from_json_with(r#"[["1e29"]]"#, &JsonOptions::default()).unwrap().cell(0, 0).unwrap().is_text());
+        assert!(Table::from_json_with(r#"[["1e29"]]"#, &strict).is_err());
+        assert!(Table::from_json_with(r#"[[1e29]]"#, &strict).is_err());
+        let mut columns = JsonOptions::default();
+        columns.parse.column_inference.insert(1, Inference::AlwaysText);
+        let table = Table::from_json_with(r#"[["00123","00123",123]]"#, &columns).unwrap();
+        assert_eq!(table.to_string(), r#"[["123","00123","123"]]"#);
+        assert!(table.cell(0, 2).unwrap().is_number());
+    }
+
+    #[test]
+    fn test_json_records() {
+        let json = r#"[{"name":"a","amount":"1.00"},{"amount":"(2.50)","name":"b"}]"#;
+        let table = Table::from_json_records(json, &JsonRecordsOptions::default()).unwrap();
+        assert!(table.has_header());
+        assert_eq!(table.to_string(), r#"[["name","amount"],["a","1.00"],["b","(2.50)"]]"#);
+        assert_eq!(table.cell(2, 1).unwrap().to_decimal(), Some(Decimal::new(-250, 2)));
+        assert_eq!(table.to_json_records().unwrap(), r#"[{"name":"a","amount":"1.00"},{"name":"b","amount":"(2.50)"}]"#);
+        assert_eq!(Table::from_json_records(&table.to_json_records().unwrap(), &JsonRecordsOptions::default()).unwrap().to_string(), table.to_string());
+        assert_eq!(Table::new().to_json_records().unwrap(), "[]");
+        assert!(Table::from_json_records(r#"[["a"]]"#, &JsonRecordsOptions::default()).is_err());
+        let mut options = JsonRecordsOptions::default();
+        options.parse.column_inference.insert(0, Inference::AlwaysText);
+        let table = Table::from_json_records(r#"[{"zip":"00123","amount":"1,234"}]"#, &options).unwrap();
+        assert_eq!(table.to_string(), r#"[["zip","amount"],["00123","1234"]]"#);
+        assert_eq!(table.to_json_records_with(JsonStyle::Tagged).unwrap(), r#"[{"zip":{"t":"s","v":"00123"},"amount":{"t":"n","v":"1234"}}]"#);
+    }
+
+    #[test]
+    fn test_json_style() {
+        let table = Table::from_json_with(r#"[["a","123"],["1.10","99999999999999999999.99"]]"#, &JsonOptions::default()).unwrap();
+        let typed = table.to_json_with(JsonStyle::Typed).unwrap();
+        assert_eq!(typed, r#"[["a",123],[1.10,99999999999999999999.99]]"#);
+        // typed json numbers are read back with every digit
+        let read = Table::try_from(typed.as_str()).unwrap();
+        assert_eq!(read.cell(1, 1), Some(Cell::Number(Decimal::from_i128_with_scale(9999999999999999999999, 2))));
+        assert_eq!(read.cell(1, 0), Some(Cell::Number(Decimal::new(110, 2))));
+        assert_eq!(read.to_json_with(JsonStyle::Typed).unwrap(), typed);
+        let mut header = Table::with_header(["amount"]);
+        header.push_row(vec![read.cell(1, 1).unwrap()]);
+        let records = header.to_json_records_with(JsonStyle::Typed).unwrap();
+        assert_eq!(records, r#"[{"amount":99999999999999999999.99}]"#);
+        let read = Table::from_json_records(&records, &JsonRecordsOptions::default()).unwrap();
+        assert_eq!(read.cell(1, 0), header.cell(1, 0));
+        // tagged json keeps the type of text that looks like a number
+        let mut table = table;
+        table.mut_cell(0, 1).unwrap().replace_value(&Cell::Text("123".to_string()));
+        let tagged = table.to_json_with(JsonStyle::Tagged).unwrap();
+        let read = Table::try_from(tagged.as_str()).unwrap();
+        assert_eq!(read.cell(0, 1), Some(Cell::Text("123".to_string())));
+        assert_eq!(read.cell(1, 1).unwrap().to_decimal(), Some(Decimal::from_i128_with_scale(9999999999999999999999, 2)));
+        assert_eq!(read.to_json_with(JsonStyle::Tagged).unwrap(), tagged);
+    }
+
     #[test]
     fn test_cell() {
         let table: Table = Table::try_from(r#"[["a","b","c"],["1","2","3"]]"#).unwrap();
@@ -416,6 +834,145 @@ mod tests {
         assert_eq!(table.to_string(), r#"[["1","2","3"],["4","5","6"],["6","8","10"]]"#);
     }
 
+    #[test]
+    fn test_value() {
+        let mut table: Table = Table::try_from(r#"[["a","b"],["1","2.5"],["2024-01-31",""]]"#).unwrap();
+        table.checked_add_value(Decimal::ONE).unwrap();
+        assert_eq!(table.to_string(), r#"[["a","b"],["2","3.5"],["2024-02-01",""]]"#);
+        table.checked_sub_value(Decimal::TWO).unwrap().checked_mul_value(Decimal::TWO).unwrap();
+        assert_eq!(table.to_string(), r#"[["a","b"],["0","3.0"],["2024-01-30",""]]"#);
+    }
+
+    #[test]
+    fn test_checked_value() {
+        let mut table: Table = Table::try_from(r#"[["a","b"],["1","79228162514264337593543950335"]]"#).unwrap();
+        let before = table.to_string();
+        assert_eq!(table.checked_add_value(Decimal::ONE).unwrap_err(), CellError::Overflow);
+        assert_eq!(table.checked_mul_value(Decimal::TWO).unwrap_err(), CellError::Overflow);
+        assert_eq!(table.checked_div_value(Decimal::ZERO).unwrap_err(), CellError::DivZero);
+        assert_eq!(table.to_string(), before);
+        table.checked_sub_value(Decimal::ONE).unwrap().checked_div_value(Decimal::TWO).unwrap();
+        assert_eq!(table.to_string(), r#"[["a","b"],["0","39614081257132168796771975167"]]"#);
+        assert_eq!(table.checked_mul_value(Decimal::from(3)).unwrap_err(), CellError::Overflow);
+    }
+
+    #[test]
+    fn test_header() {
+        let mut table = Table::with_header(["name", "2024", "2025"]);
+        assert!(table.has_header());
+        table.push_row(vec![Cell::from("a"), Cell::from("2024"), Cell::from("1")]);
+        table.push_row(vec![Cell::from("b"), Cell::from("3"), Cell::from("x")]);
+        assert_eq!(table.header().unwrap().to_string(), r#"["name","2024","2025"]"#);
+        assert!(table.header().unwrap().cell(1).is_text());
+        assert_eq!(table.col_index("2025"), Some(2));
+        assert_eq!(table.col_index("missing"), None);
+        assert_eq!(table.col_by_name("2024").unwrap().to_string(), r#"["2024","3"]"#);
+        assert_eq!(table.find_value(&Decimal::from(2024)), vec![(1, 1)]);
+        assert!(!table.contains_value("name"));
+        table.checked_mul_value(Decimal::TWO).unwrap();
+        assert_eq!(table.to_string(), r#"[["name","2024","2025"],["a","4048","2"],["b","6","x"]]"#);
+        assert!(table.rename_col("2024", "q1"));
+        assert!(!table.rename_col("2024", "q2"));
+        assert_eq!(table.header().unwrap().to_string(), r#"["name","q1","2025"]"#);
+        table.set_header(false);
+        assert!(table.header().is_none());
+        assert_eq!(table.col_index("q1"), None);
+        assert!(table.contains_value("name"));
+        let table = Table::with_header(Vec::<String>::new());
+        assert_eq!(table.rows(), 0);
+        assert!(table.header().is_none());
+        assert!(!table.has_header());
+    }
+
+    #[test]
+    fn test_header_rows() {
+        let header = || {
+            let mut table = Table::with_header(["name", "amount"]);
+            table.push_row(vec![Cell::from("a"), Cell::from("1")]);
+            table
+        };
+        // rows below the header keep the header
+        let mut table = header();
+        table.insert_row(1, vec![Cell::from("b"), Cell::from("2")]);
+        table.remove_row(2);
+        table.replace_row(1, vec![Cell::from("c"), Cell::from("3")]);
+        assert_eq!(table.header().unwrap().to_string(), r#"["name","amount"]"#);
+        // removing the header does not make a row the header
+        let mut table = header();
+        assert_eq!(table.remove_row(0).unwrap().to_string(), r#"["name","amount"]"#);
+        assert!(!table.has_header());
+        assert_eq!(table.sum_col(1), Some(Cell::from("1")));
+        // a row above the header is not the header
+        let mut table = header();
+        table.insert_row(0, vec![Cell::from("b"), Cell::from("2")]);
+        assert!(!table.has_header());
+        assert_eq!(table.to_string(), r#"[["b","2"],["name","amount"],["a","1"]]"#);
+        // replacing the header renames the columns
+        let mut table = header();
+        table.replace_row(0, vec![Cell::from("account"), Cell::from("balance")]);
+        assert_eq!(table.col_index("balance"), Some(1));
+        // a json array of arrays does not record the header
+        let table: Table = Table::try_from(header().to_string().as_str()).unwrap();
+        assert!(!table.has_header());
+    }
+
+    #[test]
+    fn test_json_header() {
+        let mut table = Table::with_header(["name", "2024", "00123"]);
+        table.push_row(vec![Cell::from("a"), Cell::from("1"), Cell::from("x")]);
+        let options = JsonOptions { header: true, ..Default::default() };
+        for json in [table.to_string(), table.to_json_with(JsonStyle::Typed).unwrap(), table.to_json_with(JsonStyle::Tagged).unwrap()] {
+            let read = Table::from_json_with(&json, &options).unwrap();
+            assert!(read.has_header());
+            assert_eq!(read.cell(0, 1), Some(Cell::Text("2024".to_string())));
+            assert_eq!(read.cell(0, 2), Some(Cell::Text("00123".to_string())));
+            assert_eq!(read.cell(1, 1), Some(Cell::from("1")));
+            assert_eq!(read.to_string(), table.to_string());
+        }
+        assert!(Table::from_json_with("[]", &options).unwrap().header().is_none());
+        // records use the header, or keys named by position without a header
+        assert_eq!(table.to_json_records().unwrap(), r#"[{"name":"a","2024":"1","00123":"x"}]"#);
+        table.set_header(false);
+        assert_eq!(table.to_json_records().unwrap(), r#"[{"column_1":"name","column_2":"2024","column_3":"00123"},{"column_1":"a","column_2":"1","column_3":"x"}]"#);
+    }
+
+    #[test]
+    fn test_sum_col() {
+        let table: Table = Table::try_from(r##"[["a","b","c","d"],["1","USD 1","x","#N/A"],["$2.50","USD 2.005","",""]]"##).unwrap();
+        assert_eq!(table.sum_col(0), Some(Cell::from("3.50")));
+        let mut table = Table::with_header(["a", "b", "c", "d"]);
+        table.push_row(vec![Cell::from("10"), Cell::from("USD 1"), Cell::from("x"), Cell::from("#N/A")]);
+        table.push_row(vec![Cell::from("$2.50"), Cell::from("USD 2.005"), Cell::Empty, Cell::from("1")]);
+        assert_eq!(table.sum_col(0), Some(Cell::from("12.50")));
+        assert_eq!(table.sum_col(1).unwrap().to_string(), "USD 3.01");
+        assert_eq!(table.sum_col(2), Some(Cell::from("0")));
+        assert_eq!(table.sum_col(3), Some(Cell::Error(CellError::NA)));
+        assert_eq!(table.sum_col(4), None);
+        table.push_row(vec![Cell::from("79228162514264337593543950335"), Cell::from("EUR 1"), Cell::Empty, Cell::Empty]);
+        assert_eq!(table.sum_col(0), Some(Cell::Error(CellError::Overflow)));
+        assert_eq!(table.sum_col(1), Some(Cell::Error(CellError::Value)));
+    }
+
+    #[test]
+    fn test_sort_by_col() {
+        let mut table = Table::with_header(["k", "v"]);
+        for (k, v) in [("a", "10"), ("b", "x"), ("c", ""), ("d", "2"), ("e", "#N/A"), ("f", "2024-01-31"), ("g", "USD 1"), ("h", "EUR 5"), ("i", "2")] {
+            table.push_row(vec![Cell::from(k), Cell::from(v)]);
+        }
+        table.sort_by_col(1, true);
+        let keys: Vec<String> = table.col(0).unwrap().iter().map(Cell::to_string).collect();
+        assert_eq!(keys, vec!["k", "d", "i", "a", "h", "g", "f", "b", "e", "c"]);
+        table.sort_by_col(1, false);
+        let keys: Vec<String> = table.col(0).unwrap().iter().map(Cell::to_string).collect();
+        assert_eq!(keys, vec!["k", "c", "e", "b", "f", "g", "h", "a", "d", "i"]);
+        // without a header every row is sorted
+        let mut table: Table = Table::try_from(r#"[["b"],["a"],["3"]]"#).unwrap();
+        table.sort_by_col(0, true);
+        assert_eq!(table.to_string(), r#"[["3"],["a"],["b"]]"#);
+        table.sort_by_col(1, true);
+        assert_eq!(table.to_string(), r#"[["3"],["a"],["b"]]"#);
+    }
+
     #[test]
     fn test_find_value() {
         let table: Table = Table::try_from(r#"[["1","2","3"],["4","5","6"],["x","y","z"],["1","5","z"]]"#).unwrap();
@@ -450,4 +1007,100 @@ mod tests {
         assert_eq!(table.to_csv().unwrap(), "1,2,3\n\"ano\"\"ther\",\"lo\nng\",\"stri\rng\"\n\"xr,ay\",y,z\n");
     }
 
+    #[test]
+    fn test_read_csv() {
+        let mut reader = "a,b,c\r\n1,2,3\r\n".as_bytes();
+        let table = Table::read_csv(&mut reader).unwrap();
+        assert_eq!(table.to_string(), r#"[["a","b","c"],["1","2","3"]]"#);
+        assert_eq!(table.cell(1, 2).unwrap().to_decimal(), Some(Decimal::from(3)));
+        // missing trailing line break and short rows
+        let table = Table::from_csv("a,b,c\n1").unwrap();
+        assert_eq!(table.to_string(), r#"[["a","b","c"],["1","",""]]"#);
+        // empty fields and empty input
+        assert_eq!(Table::from_csv(",\n\"\"\n").unwrap().to_string(), r#"[["",""],["",""]]"#);
+        assert_eq!(Table::from_csv("").unwrap().rows(), 0);
+        // typed fields
+        let table = Table::from_csv("TRUE,2024-01-31,,2024-01-31 09:30\n").unwrap();
+        assert!(table.cell(0, 0).unwrap().is_bool());
+        assert!(table.cell(0, 1).unwrap().is_date());
+        assert_eq!(table.cell(0, 2), Some(Cell::Empty));
+        assert_eq!(table.to_csv().unwrap(), "true,2024-01-31,,2024-01-31T09:30:00\n");
+        // errors use Excel error codes
+        let mut table = Table::from_csv("1,#N/A\n").unwrap();
+        table.mut_cell(0, 0).unwrap().div_value(Decimal::ZERO);
+        assert_eq!(table.to_csv().unwrap(), "#DIV/0!,#N/A\n");
+        assert!(Table::from_csv(&table.to_csv().unwrap()).unwrap().cell(0, 0).unwrap().is_divide_by_zero());
+        // money keeps its currency
+        let table = Table::from_csv("USD 1234.5,\"1,000 JPY\"\n").unwrap();
+        assert!(table.cell(0, 1).unwrap().is_money());
+        assert_eq!(table.to_csv().unwrap(), "USD 1234.50,JPY 1000\n");
+        // header
+        let options = CsvOptions { header: true, ..Default::default() };
+        let table = Table::from_csv_with("id,2024\n1,2,3\n", &options).unwrap();
+        assert!(table.has_header());
+        assert_eq!(table.header().unwrap().to_string(), r#"["id","2024",""]"#);
+        assert_eq!(table.cell(0, 1), Some(Cell::Text("2024".to_string())));
+        assert_eq!(table.col_by_name("id").unwrap().to_string(), r#"["1"]"#);
+        assert_eq!(table.to_csv_with(&options).unwrap(), "id,2024,\n1,2,3\n");
+        assert!(Table::from_csv_with("", &options).unwrap().header().is_none());
+        assert!(!Table::from_csv("id\n1\n").unwrap().has_header());
+        // malformed quoting
+        assert!(Table::from_csv("\"abc").is_err());
+        assert!(Table::from_csv("\"abc\"d,e").is_err());
+    }
+
+    #[test]
+    fn test_from_csv() {
+        // test per RFC 4180
+        // round trip of quoting for comma, double qoute or line break
+        let table: Table = Table::try_from(r##"[["1","2","3"],["ano\"ther","lo\nng","stri\rng"],["xr,ay","y","z"],["","a\r\nb",""]]"##).unwrap();
+        let csv = table.to_csv().unwrap();
+        let read = Table::from_csv(&csv).unwrap();
+        assert_eq!(read.to_string(), table.to_string());
+        assert_eq!(read.to_csv().unwrap(), csv);
+        for r in 0..table.rows() {
+            assert_eq!(read.row(r).unwrap().iter().collect::<Vec<_>>(), table.row(r).unwrap().iter().collect::<Vec<_>>());
+        }
+        // text that looks like another type is not kept as text
+        let mut table = Table::new();
+        table.push_row(vec![Cell::Text("00123".to_string()), Cell::Text("true".to_string()), Cell::Text("#N/A".to_string())]);
+        let csv = table.to_csv().unwrap();
+        let read = Table::from_csv(&csv).unwrap();
+        assert_eq!(read.cell(0, 0), Some(Cell::Number(Decimal::from(123))));
+        assert_eq!(read.cell(0, 1), Some(Cell::Bool(true)));
+        assert_eq!(read.cell(0, 2), Some(Cell::Error(CellError::NA)));
+        let options = CsvOptions { parse: ParseOptions { inference: Inference::AlwaysText, ..Default::default() }, ..Default::default() };
+        let read = Table::from_csv_with(&csv, &options).unwrap();
+        assert_eq!(read.row(0).unwrap().iter().collect::<Vec<_>>(), table.row(0).unwrap().iter().collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_csv_with() {
+        let table: Table = Table::try_from(r##"[["a","b;c"],["1","x\"y"]]"##).unwrap();
+        let options = CsvOptions { delimiter: ';', ..Default::default() };
+        assert_eq!(table.to_csv_with(&options).unwrap(), "a;\"b;c\"\n1;\"x\"\"y\"\n");
+        let options = CsvOptions { quoting: CsvQuoting::Always, terminator: CsvTerminator::CrLf, trailing_newline: false, ..Default::default() };
+        assert_eq!(table.to_csv_with(&options).unwrap(), "\"a\",\"b;c\"\r\n\"1\",\"x\"\"y\"");
+        let options = CsvOptions { escape: CsvEscape::Char('\\'), ..CsvOptions::tsv() };
+        let csv = table.to_csv_with(&options).unwrap();
+        assert_eq!(csv, "a\tb;c\n1\t\"x\\\"y\"\n");
+        let mut reader = csv.as_bytes();
+        assert_eq!(Table::read_csv_with(&mut reader, &options).unwrap().to_string(), table.to_string());
+        let options = CsvOptions { delimiter: ';', quoting: CsvQuoting::Never, ..Default::default() };
+        assert_eq!(table.to_csv_with(&options).unwrap_err().kind(), ErrorKind::InvalidInput);
+        let options = CsvOptions { delimiter: '"', ..Default::default() };
+        assert_eq!(Table::from_csv_with("a\n", &options).unwrap_err().kind(), ErrorKind::InvalidInput);
+        let options = CsvOptions { delimiter: ';', parse: ParseOptions::german(), ..Default::default() };
+        let table = Table::from_csv_with("1.234,5;1,234.5\n", &options).unwrap();
+        assert_eq!(table.cell(0, 0).unwrap().to_decimal(), Some(Decimal::new(12345, 1)));
+        assert!(table.cell(0, 1).unwrap().is_text());
+        let options = CsvOptions { parse: ParseOptions { strict: true, ..Default::default() }, ..Default::default() };
+        assert_eq!(Table::from_csv_with("1.2e5,3e-4\n", &options).unwrap().to_string(), r#"[["120000","0.0003"]]"#);
+        assert_eq!(Table::from_csv_with("1e-29\n", &options).unwrap_err().kind(), ErrorKind::InvalidData);
+        let mut options = CsvOptions::default();
+        options.parse.column_inference.insert(0, Inference::AlwaysText);
+        let table = Table::from_csv_with("00123,00123\n", &options).unwrap();
+        assert_eq!(table.to_string(), r#"[["00123","123"]]"#);
+    }
+
 }
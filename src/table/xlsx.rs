@@ -0,0 +1,206 @@
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use rust_xlsxwriter::{Format, Formula, Workbook, Worksheet, XlsxError};
+use std::io::{Error, Write};
+use std::str::FromStr;
+use super::cell::{Cell, NegativeStyle};
+use super::table::Table;
+
+/// Options used to write a `Table` as an xlsx workbook.
+///
+/// # Examples
+///
+/// ```
+/// use tablefi::{Table, XlsxOptions};
+///
+/// let table: Table = Table::try_from(r#"[["name","amount"],["a","$1,234.50"]]"#).unwrap();
+/// let options = XlsxOptions { bold_header: true, ..Default::default() };
+/// let mut xlsx: Vec<u8> = Vec::new();
+/// assert!(table.write_xlsx(&mut xlsx, &options).is_ok());
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct XlsxOptions {
+    /// Whether the first row is written in bold.
+    pub bold_header: bool,
+    /// Whether the width of each column is fit to its contents.
+    pub autofit: bool,
+}
+
+impl Default for XlsxOptions {
+
+    fn default() -> Self {
+        XlsxOptions {
+            bold_header: false,
+            autofit: true,
+        }
+    }
+
+}
+
+/// Returns the Excel number format for a cell, keeping the decimal places and presentation of
+/// the number.
+fn num_format(cell: &Cell) -> Option<String> {
+    let zeros = |n: u32| match n {
+        0 => String::new(),
+        n => format!(".{}", "0".repeat(n as usize)),
+    };
+    match cell {
+        Cell::Number(d) if d.scale() > 0 => Some(format!("0{}", zeros(d.scale()))),
+        Cell::Money(_, currency) => Some(format!("\"{} \"#,##0{}", currency, zeros(currency.minor_units()))),
+        Cell::Date(_) => Some("yyyy-mm-dd".to_string()),
+        Cell::DateTime(_) => Some("yyyy-mm-dd hh:mm:ss".to_string()),
+        Cell::Formatted(d, format) => {
+            let literal = |s: &str| match s.is_empty() {
+                true => String::new(),
+                false => format!("\"{}\"", s.replace('"', "")),
+            };
+            let (decimals, suffix) = match format.percent {
+                true => (d.scale().saturating_sub(2), format!("{}%", literal(&format.suffix.replace('%', "")))),
+                false => (d.scale(), literal(&format.suffix)),
+            };
+            let int = match format.group_separator {
+                Some(_) => "#,##0",
+                None => "0",
+            };
+            let positive = format!("{}{}{}{}", literal(&format.prefix), int, zeros(decimals), suffix);
+            Some(match format.negative {
+                NegativeStyle::Minus => positive,
+                NegativeStyle::Parentheses => format!("{};({})", positive, positive),
+                NegativeStyle::TrailingMinus => format!("{};{}-", positive, positive),
+            })
+        }
+        _ => None,
+    }
+}
+
+/// Returns the value as an Excel number, or `None` when an Excel number cannot hold the value
+/// exactly, like a number with more than 17 significant digits.
+fn exact_f64(d: &Decimal) -> Option<f64> {
+    let f = d.to_f64()?;
+    // the shortest text of the float reads back as the same value
+    Decimal::from_str(&f.to_string()).ok().filter(|value| value == d).map(|_| f)
+}
+
+/// Converts an xlsx error to an io error.
+fn xlsx_error(e: XlsxError) -> Error {
+    Error::other(e)
+}
+
+impl Table {
+
+    /// Writes the table as an xlsx workbook with a single worksheet.
+    ///
+    /// Numbers are written as numeric cells, keeping the presentation of formatted numbers like
+    /// `$1,234.50` or `12.5%`, and text is written as string cells.  Money is written as a numeric
+    /// cell with its currency code and minor units in the number format, like `"USD "#,##0.00`.
+    /// A number or amount of money that an Excel number cannot hold exactly is written as text,
+    /// so no digits are lost.  Booleans are written as boolean cells, dates as date cells and
+    /// errors as error cells, with a formula like `=#DIV/0!`.  Empty cells are left blank.
+    pub fn write_xlsx<W: Write>(&self, writer: &mut W, options: &XlsxOptions) -> std::io::Result<()> {
+        let mut workbook = Workbook::new();
+        self.write_worksheet(workbook.add_worksheet(), options).map_err(xlsx_error)?;
+        writer.write_all(&workbook.save_to_buffer().map_err(xlsx_error)?)
+    }
+
+    /// Writes tables as an xlsx workbook with a worksheet for each named table.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tablefi::{Table, XlsxOptions};
+    ///
+    /// let q1: Table = Table::try_from(r#"[["amount"],["1.00"]]"#).unwrap();
+    /// let q2: Table = Table::try_from(r#"[["amount"],["2.00"]]"#).unwrap();
+    /// let mut xlsx: Vec<u8> = Vec::new();
+    /// assert!(Table::write_xlsx_sheets(&mut xlsx, &[("Q1", &q1), ("Q2", &q2)], &XlsxOptions::default()).is_ok());
+    /// ```
+    pub fn write_xlsx_sheets<W: Write>(writer: &mut W, sheets: &[(&str, &Table)], options: &XlsxOptions) -> std::io::Result<()> {
+        let mut workbook = Workbook::new();
+        for (name, table) in sheets {
+            let worksheet = workbook.add_worksheet();
+            worksheet.set_name(*name).map_err(xlsx_error)?;
+            table.write_worksheet(worksheet, options).map_err(xlsx_error)?;
+        }
+        writer.write_all(&workbook.save_to_buffer().map_err(xlsx_error)?)
+    }
+
+    /// Writes the cells of the table to a worksheet.
+    fn write_worksheet(&self, worksheet: &mut Worksheet, options: &XlsxOptions) -> Result<(), XlsxError> {
+        for r in 0..self.rows() {
+            let row = u32::try_from(r).map_err(|_| XlsxError::RowColumnLimitError)?;
+            for (c, cell) in self.row(r).unwrap_or_default().iter().enumerate() {
+                let col = u16::try_from(c).map_err(|_| XlsxError::RowColumnLimitError)?;
+                let mut format = Format::new();
+                if options.bold_header && r == 0 {
+                    format = format.set_bold();
+                }
+                if let Some(num_format) = num_format(cell) {
+                    format = format.set_num_format(num_format);
+                }
+                match cell {
+                    Cell::Number(d) | Cell::Formatted(d, _) | Cell::Money(d, _) => match exact_f64(d) {
+                        Some(f) => worksheet.write_number_with_format(row, col, f, &format)?,
+                        None => worksheet.write_string_with_format(row, col, cell.to_string(), &format)?,
+                    },
+                    Cell::Bool(b) => worksheet.write_boolean_with_format(row, col, *b, &format)?,
+                    Cell::Date(d) => worksheet.write_datetime_with_format(row, col, d, &format)?,
+                    Cell::DateTime(dt) => worksheet.write_datetime_with_format(row, col, dt, &format)?,
+                    Cell::Empty => continue,
+                    Cell::Text(s) => worksheet.write_string_with_format(row, col, s, &format)?,
+                    Cell::Error(e) => worksheet.write_formula_with_format(row, col, Formula::new(format!("={}", e.code())).set_result(e.code()), &format)?,
+                };
+            }
+        }
+        if options.autofit {
+            worksheet.autofit();
+        }
+        Ok(())
+    }
+
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_num_format() {
+        assert_eq!(num_format(&Cell::from("abc")), None);
+        assert_eq!(num_format(&Cell::from("12")), None);
+        assert_eq!(num_format(&Cell::from("1.20")).unwrap(), "0.00");
+        assert_eq!(num_format(&Cell::from("$1,234.50")).unwrap(), "\"$\"#,##0.00");
+        assert_eq!(num_format(&Cell::from("(1,234.50)")).unwrap(), "#,##0.00;(#,##0.00)");
+        assert_eq!(num_format(&Cell::from("12.50-")).unwrap(), "0.00;0.00-");
+        assert_eq!(num_format(&Cell::from("12.5%")).unwrap(), "0.0%");
+        assert_eq!(num_format(&Cell::from("12 %")).unwrap(), "0\" \"%");
+        assert_eq!(num_format(&Cell::from("12 €")).unwrap(), "0\" €\"");
+        assert_eq!(num_format(&Cell::from("2024-01-31")).unwrap(), "yyyy-mm-dd");
+        assert_eq!(num_format(&Cell::from("USD 1.5")).unwrap(), "\"USD \"#,##0.00");
+        assert_eq!(num_format(&Cell::from("JPY 150")).unwrap(), "\"JPY \"#,##0");
+    }
+
+    #[test]
+    fn test_exact_f64() {
+        assert_eq!(exact_f64(&Decimal::new(1, 1)), Some(0.1));
+        assert_eq!(exact_f64(&Decimal::new(123450, 2)), Some(1234.5));
+        assert_eq!(exact_f64(&Decimal::from(-3)), Some(-3.0));
+        assert_eq!(exact_f64(&Decimal::from_str("12345678901234567890.12").unwrap()), None);
+        assert_eq!(exact_f64(&Decimal::MAX), None);
+    }
+
+    #[test]
+    fn test_write_xlsx() {
+        let table: Table = Table::try_from(r#"[["name","amount"],["a","$1,234.50"],["b","12.5%"]]"#).unwrap();
+        let mut writer: Vec<u8> = Vec::new();
+        assert!(table.write_xlsx(&mut writer, &XlsxOptions { bold_header: true, ..Default::default() }).is_ok());
+        // xlsx is a zip archive
+        assert!(writer.starts_with(b"PK"));
+        let mut writer: Vec<u8> = Vec::new();
+        assert!(Table::write_xlsx_sheets(&mut writer, &[("a", &table), ("b", &Table::new())], &XlsxOptions::default()).is_ok());
+        assert!(Table::write_xlsx_sheets(&mut writer, &[("a/b", &table)], &XlsxOptions::default()).is_err());
+        let table: Table = Table::try_from(r##"[["12345678901234567890.12","USD 1.5","#DIV/0!","#N/A"]]"##).unwrap();
+        let mut writer: Vec<u8> = Vec::new();
+        assert!(table.write_xlsx(&mut writer, &XlsxOptions::default()).is_ok());
+    }
+
+}